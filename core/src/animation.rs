@@ -0,0 +1,252 @@
+use super::*;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+pub const FRAME_INTERVAL: Duration = Duration::from_micros(16_667);
+
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    #[default]
+    EaseOut,
+    EaseInOut,
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match *self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Self::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, t),
+        }
+    }
+}
+
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    let bezier = |a: f32, b: f32, t: f32| {
+        let u = 1.0 - t;
+        3.0 * u * u * t * a + 3.0 * u * t * t * b + t * t * t
+    };
+    let mut lo = 0.0;
+    let mut hi = 1.0;
+    let mut t = x;
+    for _ in 0..24 {
+        let v = bezier(x1, x2, t);
+        if (v - x).abs() < 1e-5 {
+            break;
+        }
+        if v < x {
+            lo = t;
+        } else {
+            hi = t;
+        }
+        t = (lo + hi) / 2.0;
+    }
+    bezier(y1, y2, t)
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Transition {
+    pub duration: Duration,
+    pub easing: Easing,
+}
+
+impl Transition {
+    #[inline]
+    pub fn new(duration: Duration, easing: Easing) -> Self {
+        Self { duration, easing }
+    }
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(150),
+            easing: Easing::EaseOut,
+        }
+    }
+}
+
+pub trait Interpolate: Copy + PartialEq + 'static {
+    fn interpolate(&self, to: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    #[inline]
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        self + (to - self) * t
+    }
+}
+
+impl Interpolate for LogicalPosition<f32> {
+    #[inline]
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        LogicalPosition::new(self.x.interpolate(&to.x, t), self.y.interpolate(&to.y, t))
+    }
+}
+
+impl Interpolate for LogicalSize<f32> {
+    #[inline]
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        LogicalSize::new(
+            self.width.interpolate(&to.width, t),
+            self.height.interpolate(&to.height, t),
+        )
+    }
+}
+
+impl Interpolate for LogicalRect<f32> {
+    #[inline]
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        LogicalRect::new(
+            self.left.interpolate(&to.left, t),
+            self.top.interpolate(&to.top, t),
+            self.right.interpolate(&to.right, t),
+            self.bottom.interpolate(&to.bottom, t),
+        )
+    }
+}
+
+impl<const N: usize> Interpolate for [f32; N] {
+    #[inline]
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        std::array::from_fn(|i| self[i].interpolate(&to[i], t))
+    }
+}
+
+#[derive(Debug)]
+struct Animation<V> {
+    from: V,
+    to: V,
+    start: Instant,
+    transition: Transition,
+}
+
+impl<V: Interpolate> Animation<V> {
+    fn value(&self, now: Instant) -> V {
+        let t = self.progress(now);
+        if t >= 1.0 {
+            return self.to;
+        }
+        self.from
+            .interpolate(&self.to, self.transition.easing.apply(t))
+    }
+
+    fn progress(&self, now: Instant) -> f32 {
+        if self.transition.duration.is_zero() {
+            return 1.0;
+        }
+        let elapsed = now.saturating_duration_since(self.start);
+        (elapsed.as_secs_f32() / self.transition.duration.as_secs_f32()).min(1.0)
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    animation: Box<dyn Any>,
+    used: bool,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Animations {
+    entries: RefCell<HashMap<(AnyHandle, &'static str), Entry>>,
+    running: RefCell<HashSet<Id>>,
+}
+
+impl Animations {
+    pub(crate) fn animate<V: Interpolate>(
+        &self,
+        handle: AnyHandle,
+        property: &'static str,
+        target: V,
+        transition: &Transition,
+        now: Instant,
+    ) -> V {
+        let mut entries = self.entries.borrow_mut();
+        let entry = entries.entry((handle, property)).or_insert_with(|| Entry {
+            animation: Box::new(Animation {
+                from: target,
+                to: target,
+                start: now,
+                transition: *transition,
+            }),
+            used: true,
+        });
+        entry.used = true;
+        let Some(animation) = entry.animation.downcast_mut::<Animation<V>>() else {
+            entry.animation = Box::new(Animation {
+                from: target,
+                to: target,
+                start: now,
+                transition: *transition,
+            });
+            return target;
+        };
+        if animation.to != target {
+            animation.from = animation.value(now);
+            animation.to = target;
+            animation.start = now;
+            animation.transition = *transition;
+        }
+        let value = animation.value(now);
+        if animation.progress(now) < 1.0 {
+            self.running.borrow_mut().insert(handle.id());
+        }
+        value
+    }
+
+    pub(crate) fn is_running<V: Interpolate>(
+        &self,
+        handle: AnyHandle,
+        property: &'static str,
+        now: Instant,
+    ) -> bool {
+        self.entries
+            .borrow()
+            .get(&(handle, property))
+            .and_then(|entry| entry.animation.downcast_ref::<Animation<V>>())
+            .is_some_and(|animation| animation.progress(now) < 1.0)
+    }
+
+    pub(crate) fn begin_frame(&mut self) {
+        self.running.get_mut().clear();
+        self.entries
+            .get_mut()
+            .values_mut()
+            .for_each(|entry| entry.used = false);
+    }
+
+    pub(crate) fn keep(&mut self, f: impl Fn(Id) -> bool) {
+        self.entries
+            .get_mut()
+            .iter_mut()
+            .filter(|((handle, _), _)| f(handle.id()))
+            .for_each(|(_, entry)| entry.used = true);
+    }
+
+    pub(crate) fn end_frame(&mut self) {
+        self.entries.get_mut().retain(|_, entry| entry.used);
+    }
+
+    #[inline]
+    pub(crate) fn is_animating(&self) -> bool {
+        !self.running.borrow().is_empty()
+    }
+
+    pub(crate) fn running(&self) -> Vec<Id> {
+        self.running.borrow().iter().copied().collect()
+    }
+}
@@ -0,0 +1,197 @@
+use super::*;
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+struct Press {
+    button: MouseButton,
+    mouse_state: MouseState,
+    since: Instant,
+    count: u32,
+}
+
+#[derive(Debug)]
+pub(crate) struct Clicks {
+    last: Option<Press>,
+    armed: bool,
+    pub interval: Duration,
+    pub distance: f32,
+    pub long_press_delay: Duration,
+}
+
+impl Clicks {
+    pub fn new() -> Self {
+        Self {
+            last: None,
+            armed: false,
+            interval: Duration::from_millis(500),
+            distance: 4.0,
+            long_press_delay: Duration::from_millis(800),
+        }
+    }
+
+    fn is_near(&self, a: &LogicalPosition<f32>, b: &LogicalPosition<f32>) -> bool {
+        (a.x - b.x).abs() <= self.distance && (a.y - b.y).abs() <= self.distance
+    }
+
+    pub fn mouse_input(&mut self, m: &mut MouseInput, now: Instant) {
+        match m.button_state {
+            ButtonState::Pressed => {
+                let count = match self.last.as_ref() {
+                    Some(last)
+                        if last.button == m.button
+                            && now.duration_since(last.since) <= self.interval
+                            && self
+                                .is_near(&last.mouse_state.position, &m.mouse_state.position) =>
+                    {
+                        last.count + 1
+                    }
+                    _ => 1,
+                };
+                self.last = Some(Press {
+                    button: m.button,
+                    mouse_state: m.mouse_state.clone(),
+                    since: now,
+                    count,
+                });
+                self.armed = true;
+                m.click_count = count;
+            }
+            ButtonState::Released => {
+                m.click_count = match self.last.as_ref() {
+                    Some(last) if last.button == m.button => {
+                        self.armed = false;
+                        last.count
+                    }
+                    _ => 1,
+                };
+            }
+        }
+    }
+
+    pub fn cursor_moved(&mut self, position: &LogicalPosition<f32>) {
+        if self
+            .last
+            .as_ref()
+            .is_some_and(|last| !self.is_near(&last.mouse_state.position, position))
+        {
+            self.armed = false;
+        }
+    }
+
+    pub fn cancel(&mut self) {
+        self.last = None;
+        self.armed = false;
+    }
+
+    pub fn long_press(&mut self, now: Instant) -> Option<LongPress> {
+        let last = self.last.as_ref()?;
+        if !self.armed || now < last.since + self.long_press_delay {
+            return None;
+        }
+        self.armed = false;
+        Some(LongPress {
+            button: last.button,
+            mouse_state: last.mouse_state.clone(),
+        })
+    }
+
+    pub fn deadline(&self) -> Option<Instant> {
+        let last = self.last.as_ref()?;
+        self.armed.then_some(last.since + self.long_press_delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(button: MouseButton, button_state: ButtonState, x: f32, y: f32) -> MouseInput {
+        MouseInput {
+            button,
+            button_state,
+            click_count: 0,
+            mouse_state: MouseState {
+                position: LogicalPosition::new(x, y),
+                buttons: MouseButtons::new(),
+                modifiers: Modifiers::NONE,
+            },
+        }
+    }
+
+    fn click(clicks: &mut Clicks, x: f32, y: f32, now: Instant) -> u32 {
+        let mut m = input(MouseButton::Left, ButtonState::Pressed, x, y);
+        clicks.mouse_input(&mut m, now);
+        let pressed = m.click_count;
+        let mut m = input(MouseButton::Left, ButtonState::Released, x, y);
+        clicks.mouse_input(&mut m, now);
+        assert_eq!(m.click_count, pressed);
+        pressed
+    }
+
+    #[test]
+    fn counts_clicks_within_interval() {
+        let mut clicks = Clicks::new();
+        let now = Instant::now();
+        assert_eq!(click(&mut clicks, 10.0, 10.0, now), 1);
+        let now = now + Duration::from_millis(200);
+        assert_eq!(click(&mut clicks, 10.0, 10.0, now), 2);
+        let now = now + clicks.interval;
+        assert_eq!(click(&mut clicks, 10.0, 10.0, now), 3);
+    }
+
+    #[test]
+    fn resets_after_timeout() {
+        let mut clicks = Clicks::new();
+        let now = Instant::now();
+        assert_eq!(click(&mut clicks, 10.0, 10.0, now), 1);
+        let now = now + clicks.interval + Duration::from_millis(1);
+        assert_eq!(click(&mut clicks, 10.0, 10.0, now), 1);
+    }
+
+    #[test]
+    fn resets_beyond_distance() {
+        let mut clicks = Clicks::new();
+        let now = Instant::now();
+        assert_eq!(click(&mut clicks, 10.0, 10.0, now), 1);
+        assert_eq!(click(&mut clicks, 14.0, 6.0, now), 2);
+        assert_eq!(click(&mut clicks, 18.5, 6.0, now), 1);
+        assert_eq!(click(&mut clicks, 18.5, 1.0, now), 1);
+    }
+
+    #[test]
+    fn resets_on_other_button() {
+        let mut clicks = Clicks::new();
+        let now = Instant::now();
+        assert_eq!(click(&mut clicks, 10.0, 10.0, now), 1);
+        let mut m = input(MouseButton::Right, ButtonState::Pressed, 10.0, 10.0);
+        clicks.mouse_input(&mut m, now);
+        assert_eq!(m.click_count, 1);
+        assert_eq!(click(&mut clicks, 10.0, 10.0, now), 1);
+    }
+
+    #[test]
+    fn long_press() {
+        let mut clicks = Clicks::new();
+        let now = Instant::now();
+        let mut m = input(MouseButton::Left, ButtonState::Pressed, 10.0, 10.0);
+        clicks.mouse_input(&mut m, now);
+        assert_eq!(clicks.deadline(), Some(now + clicks.long_press_delay));
+        assert!(clicks.long_press(now).is_none());
+        let later = now + clicks.long_press_delay;
+        assert!(clicks.long_press(later).is_some());
+        assert!(clicks.long_press(later).is_none());
+        assert_eq!(clicks.deadline(), None);
+    }
+
+    #[test]
+    fn moving_away_cancels_long_press() {
+        let mut clicks = Clicks::new();
+        let now = Instant::now();
+        let mut m = input(MouseButton::Left, ButtonState::Pressed, 10.0, 10.0);
+        clicks.mouse_input(&mut m, now);
+        clicks.cursor_moved(&LogicalPosition::new(12.0, 12.0));
+        assert!(clicks.deadline().is_some());
+        clicks.cursor_moved(&LogicalPosition::new(20.0, 10.0));
+        assert!(clicks.long_press(now + clicks.long_press_delay).is_none());
+    }
+}
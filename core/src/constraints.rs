@@ -0,0 +1,134 @@
+use super::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Constraints {
+    pub min: LogicalSize<f32>,
+    pub max: LogicalSize<f32>,
+}
+
+impl Constraints {
+    #[inline]
+    pub fn new(min: impl Into<LogicalSize<f32>>, max: impl Into<LogicalSize<f32>>) -> Self {
+        Self {
+            min: min.into(),
+            max: max.into(),
+        }
+    }
+
+    #[inline]
+    pub fn tight(size: impl Into<LogicalSize<f32>>) -> Self {
+        let size = size.into();
+        Self {
+            min: size,
+            max: size,
+        }
+    }
+
+    #[inline]
+    pub fn loose(max: impl Into<LogicalSize<f32>>) -> Self {
+        Self {
+            min: LogicalSize::new(0.0, 0.0),
+            max: max.into(),
+        }
+    }
+
+    #[inline]
+    pub fn unbounded() -> Self {
+        Self::loose((f32::INFINITY, f32::INFINITY))
+    }
+
+    #[inline]
+    pub fn is_tight(&self) -> bool {
+        self.min == self.max
+    }
+
+    #[inline]
+    pub fn has_bounded_width(&self) -> bool {
+        self.max.width.is_finite()
+    }
+
+    #[inline]
+    pub fn has_bounded_height(&self) -> bool {
+        self.max.height.is_finite()
+    }
+
+    #[inline]
+    pub fn constrain(&self, size: LogicalSize<f32>) -> LogicalSize<f32> {
+        LogicalSize::new(
+            size.width.min(self.max.width).max(self.min.width),
+            size.height.min(self.max.height).max(self.min.height),
+        )
+    }
+
+    #[inline]
+    pub fn shrink(&self, width: f32, height: f32) -> Self {
+        Self {
+            min: LogicalSize::new(
+                (self.min.width - width).max(0.0),
+                (self.min.height - height).max(0.0),
+            ),
+            max: LogicalSize::new(
+                (self.max.width - width).max(0.0),
+                (self.max.height - height).max(0.0),
+            ),
+        }
+    }
+
+    #[inline]
+    pub fn available(&self, fallback: LogicalSize<f32>) -> LogicalSize<f32> {
+        LogicalSize::new(
+            if self.has_bounded_width() {
+                self.max.width
+            } else {
+                fallback.width
+            },
+            if self.has_bounded_height() {
+                self.max.height
+            } else {
+                fallback.height
+            },
+        )
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SizeHint {
+    pub min: LogicalSize<f32>,
+    pub preferred: LogicalSize<f32>,
+    pub max: LogicalSize<f32>,
+}
+
+impl SizeHint {
+    #[inline]
+    pub fn fixed(size: LogicalSize<f32>) -> Self {
+        Self {
+            min: size,
+            preferred: size,
+            max: size,
+        }
+    }
+
+    pub fn new(size: LogicalSize<f32>, size_types: SizeTypes) -> Self {
+        let axis = |value: f32, size_type: SizeType| match size_type {
+            SizeType::Fix => (value, value),
+            SizeType::Flexible => (0.0, f32::INFINITY),
+        };
+        let (min_width, max_width) = axis(size.width, size_types.width);
+        let (min_height, max_height) = axis(size.height, size_types.height);
+        Self {
+            min: LogicalSize::new(min_width, min_height),
+            preferred: size,
+            max: LogicalSize::new(max_width, max_height),
+        }
+    }
+
+    #[inline]
+    pub fn is_flexible_width(&self) -> bool {
+        self.max.width > self.preferred.width
+    }
+
+    #[inline]
+    pub fn is_flexible_height(&self) -> bool {
+        self.max.height > self.preferred.height
+    }
+}
@@ -0,0 +1,244 @@
+use super::*;
+use std::any::Any;
+
+pub struct StartDrag {
+    payload: Option<Box<dyn Any>>,
+    preview: Option<String>,
+}
+
+impl StartDrag {
+    #[inline]
+    pub fn new(payload: impl Any) -> Self {
+        Self {
+            payload: Some(Box::new(payload)),
+            preview: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_preview(mut self, text: impl Into<String>) -> Self {
+        self.preview = Some(text.into());
+        self
+    }
+}
+
+impl std::fmt::Debug for StartDrag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "StartDrag {{ preview: {:?} }}", self.preview)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DragKind {
+    Enter,
+    Over,
+    Leave,
+    Drop,
+    End { dropped: bool },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DragFeedback {
+    Entered,
+    Left,
+    Dropped,
+    Cancelled,
+}
+
+pub struct DragEvent {
+    pub kind: DragKind,
+    pub source: AnyHandle,
+    pub position: LogicalPosition<f32>,
+    payload: Option<Box<dyn Any>>,
+    accepted: bool,
+}
+
+impl DragEvent {
+    #[inline]
+    pub fn is<T: Any>(&self) -> bool {
+        self.payload.as_ref().is_some_and(|p| p.is::<T>())
+    }
+
+    #[inline]
+    pub fn payload<T: Any>(&self) -> Option<&T> {
+        self.payload.as_ref()?.downcast_ref::<T>()
+    }
+
+    #[inline]
+    pub fn take_payload<T: Any>(&mut self) -> Option<T> {
+        if self.kind != DragKind::Drop || !self.is::<T>() {
+            return None;
+        }
+        self.payload.take()?.downcast::<T>().ok().map(|p| *p)
+    }
+
+    #[inline]
+    pub fn accept(&mut self) {
+        self.accepted = true;
+    }
+
+    #[inline]
+    pub fn is_accepted(&self) -> bool {
+        self.accepted
+    }
+}
+
+impl std::fmt::Debug for DragEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DragEvent")
+            .field("kind", &self.kind)
+            .field("source", &self.source)
+            .field("position", &self.position)
+            .field("accepted", &self.accepted)
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+pub struct DragPreview {
+    id: Id,
+}
+
+impl HasId for DragPreview {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for DragPreview {
+    fn input(&mut self, _ctx: &Context, _input: &Input, _events: &mut Events) -> ControlFlow {
+        ControlFlow::Continue
+    }
+
+    fn apply(&mut self, _funcs: &mut ApplyFuncs) {}
+
+    fn size(&self, _ctx: &LayoutContext) -> LogicalSize<f32> {
+        LogicalSize::new(0.0, 0.0)
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        SizeTypes::fix()
+    }
+
+    fn layout(&self, _lc: LayoutContext, _result: &mut LayoutConstructor) {}
+}
+
+pub(crate) struct Drag {
+    pub source: AnyHandle,
+    pub payload: Option<Box<dyn Any>>,
+    pub preview: Option<String>,
+    pub position: LogicalPosition<f32>,
+    pub target: Option<AnyHandle>,
+}
+
+impl Drag {
+    pub fn new(source: AnyHandle, start: &mut StartDrag, position: LogicalPosition<f32>) -> Self {
+        Self {
+            source,
+            payload: start.payload.take(),
+            preview: start.preview.take(),
+            position,
+            target: None,
+        }
+    }
+
+    pub fn event(&mut self, kind: DragKind) -> DragEvent {
+        DragEvent {
+            kind,
+            source: self.source,
+            position: self.position,
+            payload: self.payload.take(),
+            accepted: false,
+        }
+    }
+
+    pub fn restore(&mut self, event: DragEvent) -> bool {
+        self.payload = event.payload;
+        event.accepted
+    }
+}
+
+impl std::fmt::Debug for Drag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Drag")
+            .field("source", &self.source)
+            .field("preview", &self.preview)
+            .field("position", &self.position)
+            .field("target", &self.target)
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct DragPreviews {
+    widget: DragPreview,
+    pub padding: LogicalRect<f32>,
+    pub offset: LogicalPosition<f32>,
+}
+
+impl DragPreviews {
+    pub fn new() -> Self {
+        Self {
+            widget: DragPreview { id: Id::new() },
+            padding: LogicalRect::new(5.0, 3.0, 5.0, 3.0),
+            offset: LogicalPosition::new(12.0, 12.0),
+        }
+    }
+
+    pub fn id(&self) -> Id {
+        self.widget.id
+    }
+
+    pub fn layout(&self, drag: &Drag, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let Some(text) = drag.preview.as_ref() else {
+            return;
+        };
+        let style = lc.ctx.style(&self.widget, "drag_preview");
+        let Some(font) = style.font.as_ref().or(lc.ctx.default_font.as_ref()) else {
+            return;
+        };
+        let text_rect = bounding_box_with_str(lc.ctx, font, text);
+        let size = LogicalSize::new(
+            text_rect.right + self.padding.left + self.padding.right,
+            text_rect.bottom + self.padding.top + self.padding.bottom,
+        );
+        let rect = LogicalRect::from_position_size(
+            (
+                drag.position.x + self.offset.x,
+                drag.position.y + self.offset.y,
+            ),
+            size,
+        );
+        let layer = u32::MAX;
+        result.push(
+            &lc,
+            LayoutElement::area(
+                &self.widget,
+                WidgetState::None,
+                rect,
+                &lc.ancestors,
+                layer,
+                false,
+            )
+            .with_style(&style, WidgetState::None, false)
+            .with_opacity(0.8),
+        );
+        result.push(
+            &lc,
+            LayoutElement::text(
+                &self.widget,
+                WidgetState::None,
+                LogicalRect::from_position_size(
+                    (rect.left + self.padding.left, rect.top + self.padding.top),
+                    text_rect.size(),
+                ),
+                &lc.ancestors,
+                Some(font.clone()),
+                text.clone(),
+                layer,
+                false,
+            )
+            .with_style(&style, WidgetState::None, false),
+        );
+    }
+}
@@ -17,6 +17,12 @@ impl StateChanged {
 #[derive(Clone, Copy, Debug)]
 pub struct SetFocus;
 
+#[derive(Clone, Copy, Debug)]
+pub struct CapturePointer;
+
+#[derive(Clone, Copy, Debug)]
+pub struct ReleasePointer;
+
 pub struct Event {
     handle: AnyHandle,
     object: Box<dyn Any>,
@@ -69,6 +75,16 @@ impl Event {
         self.object.downcast_ref::<SetFocus>().is_some()
     }
 
+    #[inline]
+    pub fn is_capture_pointer(&self) -> bool {
+        self.object.downcast_ref::<CapturePointer>().is_some()
+    }
+
+    #[inline]
+    pub fn is_release_pointer(&self) -> bool {
+        self.object.downcast_ref::<ReleasePointer>().is_some()
+    }
+
     #[inline]
     pub fn downcast_ref<T>(&self) -> Option<&T>
     where
@@ -76,6 +92,14 @@ impl Event {
     {
         self.object.downcast_ref::<T>()
     }
+
+    #[inline]
+    pub fn downcast_mut<T>(&mut self) -> Option<&mut T>
+    where
+        T: Any,
+    {
+        self.object.downcast_mut::<T>()
+    }
 }
 
 #[inline]
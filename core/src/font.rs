@@ -150,6 +150,15 @@ impl Font {
             (bounding.y_max - bounding.y_min) as f32 * scale,
         )
     }
+
+    #[inline]
+    pub fn ascent(&self) -> f32 {
+        let face =
+            rustybuzz::Face::from_slice(&self.face.file.data, self.face.index as u32).unwrap();
+        let size = self.size * 96.0 / 72.0;
+        let scale = size / face.units_per_em() as f32;
+        face.global_bounding_box().y_max as f32 * scale
+    }
 }
 
 mod bounding_box {
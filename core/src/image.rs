@@ -0,0 +1,105 @@
+use super::*;
+use std::sync::Arc;
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct RgbaImage {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+impl RgbaImage {
+    #[inline]
+    pub fn new(width: u32, height: u32, data: Vec<u8>) -> Option<Self> {
+        (data.len() == width as usize * height as usize * 4).then_some(Self {
+            width,
+            height,
+            data,
+        })
+    }
+
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum ImageSource {
+    Rgba(Arc<RgbaImage>),
+    Id { id: u64, size: LogicalSize<f32> },
+}
+
+impl ImageSource {
+    #[inline]
+    pub fn id(id: u64, size: impl Into<LogicalSize<f32>>) -> Self {
+        Self::Id {
+            id,
+            size: size.into(),
+        }
+    }
+
+    #[inline]
+    pub fn size(&self) -> LogicalSize<f32> {
+        match self {
+            Self::Rgba(image) => LogicalSize::new(image.width as f32, image.height as f32),
+            Self::Id { size, .. } => *size,
+        }
+    }
+}
+
+impl From<RgbaImage> for ImageSource {
+    #[inline]
+    fn from(value: RgbaImage) -> Self {
+        Self::Rgba(Arc::new(value))
+    }
+}
+
+impl From<Arc<RgbaImage>> for ImageSource {
+    #[inline]
+    fn from(value: Arc<RgbaImage>) -> Self {
+        Self::Rgba(value)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum ImageFit {
+    #[default]
+    Contain,
+    Cover,
+    Fill,
+    None,
+}
+
+impl ImageFit {
+    pub fn apply(&self, size: LogicalSize<f32>, rect: &LogicalRect<f32>) -> LogicalRect<f32> {
+        let area = rect.size();
+        if size.width <= 0.0 || size.height <= 0.0 {
+            return *rect;
+        }
+        let scale = match self {
+            Self::Fill => return *rect,
+            Self::None => 1.0,
+            Self::Contain => (area.width / size.width).min(area.height / size.height),
+            Self::Cover => (area.width / size.width).max(area.height / size.height),
+        };
+        let size = LogicalSize::new(size.width * scale, size.height * scale);
+        LogicalRect::from_position_size(
+            LogicalPosition::new(
+                rect.left + (area.width - size.width) / 2.0,
+                rect.top + (area.height - size.height) / 2.0,
+            ),
+            size,
+        )
+    }
+}
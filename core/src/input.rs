@@ -1,15 +1,86 @@
 use super::*;
 
+// The hosts report the Windows keys (VK_LWIN and VK_RWIN) as `VirtualKey::Other`.
+pub const LEFT_META: VirtualKey = VirtualKey::Other(0x5b);
+pub const RIGHT_META: VirtualKey = VirtualKey::Other(0x5c);
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub meta: bool,
+}
+
+impl Modifiers {
+    pub const NONE: Self = Self {
+        shift: false,
+        ctrl: false,
+        alt: false,
+        meta: false,
+    };
+    pub const SHIFT: Self = Self {
+        shift: true,
+        ..Self::NONE
+    };
+    pub const CTRL: Self = Self {
+        ctrl: true,
+        ..Self::NONE
+    };
+    pub const ALT: Self = Self {
+        alt: true,
+        ..Self::NONE
+    };
+    pub const META: Self = Self {
+        meta: true,
+        ..Self::NONE
+    };
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::NONE
+    }
+}
+
+impl std::ops::BitOr for Modifiers {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self {
+            shift: self.shift || rhs.shift,
+            ctrl: self.ctrl || rhs.ctrl,
+            alt: self.alt || rhs.alt,
+            meta: self.meta || rhs.meta,
+        }
+    }
+}
+
+impl std::ops::BitOrAssign for Modifiers {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct MouseState {
     pub position: LogicalPosition<f32>,
     pub buttons: MouseButtons,
+    pub modifiers: Modifiers,
 }
 
 #[derive(Clone, Debug)]
 pub struct MouseInput {
     pub button: MouseButton,
     pub button_state: ButtonState,
+    pub click_count: u32,
+    pub mouse_state: MouseState,
+}
+
+#[derive(Clone, Debug)]
+pub struct LongPress {
+    pub button: MouseButton,
     pub mouse_state: MouseState,
 }
 
@@ -26,14 +97,58 @@ pub struct CursorLeft {
 #[derive(Clone, Debug)]
 pub struct MouseWheel {
     pub axis: MouseWheelAxis,
-    pub distance: i32,
+    pub distance: f32,
     pub mouse_state: MouseState,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TouchPhase {
+    Started,
+    Moved,
+    Ended,
+    Cancelled,
+}
+
+#[derive(Clone, Debug)]
+pub struct Touch {
+    pub id: u64,
+    pub phase: TouchPhase,
+    pub position: LogicalPosition<f32>,
+    pub pressure: Option<f32>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PenInput {
+    pub phase: TouchPhase,
+    pub position: LogicalPosition<f32>,
+    pub pressure: f32,
+    pub tilt_x: f32,
+    pub tilt_y: f32,
+    pub eraser: bool,
+    pub barrel_button: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GestureKind {
+    Pan { dx: f32, dy: f32 },
+    Pinch { scale: f32 },
+    Scroll { dx: f32, dy: f32 },
+    Fling { vx: f32, vy: f32 },
+}
+
+#[derive(Clone, Debug)]
+pub struct Gesture {
+    pub kind: GestureKind,
+    pub position: LogicalPosition<f32>,
+}
+
 #[derive(Clone, Debug)]
 pub struct KeyInput {
     pub vkey: VirtualKey,
+    pub scan_code: ScanCode,
     pub key_state: KeyState,
+    pub modifiers: Modifiers,
+    pub repeat: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -55,10 +170,99 @@ pub enum Input {
     MouseInput(MouseInput),
     CursorMoved(CursorMoved),
     CursorLeft(CursorLeft),
+    MouseEnter(CursorMoved),
+    MouseLeave(CursorLeft),
+    LongPress(LongPress),
     MouseWheel(MouseWheel),
+    Touch(Touch),
+    Pen(PenInput),
+    Gesture(Gesture),
     KeyInput(KeyInput),
     CharInput(char),
     ImeBeginComposition,
     ImeUpdateComposition(Composition),
     ImeEndComposition(Option<String>),
+    FocusLost,
+    Tick,
+}
+
+impl Input {
+    #[inline]
+    pub fn position(&self) -> Option<LogicalPosition<f32>> {
+        match self {
+            Self::MouseInput(m) => Some(m.mouse_state.position),
+            Self::CursorMoved(m) => Some(m.mouse_state.position),
+            Self::LongPress(m) => Some(m.mouse_state.position),
+            Self::MouseWheel(m) => Some(m.mouse_state.position),
+            Self::Touch(t) => Some(t.position),
+            Self::Pen(p) => Some(p.position),
+            Self::Gesture(g) => Some(g.position),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn mouse_state_mut(&mut self) -> Option<&mut MouseState> {
+        match self {
+            Self::MouseInput(m) => Some(&mut m.mouse_state),
+            Self::CursorMoved(m) => Some(&mut m.mouse_state),
+            Self::CursorLeft(m) => Some(&mut m.mouse_state),
+            Self::MouseEnter(m) => Some(&mut m.mouse_state),
+            Self::MouseLeave(m) => Some(&mut m.mouse_state),
+            Self::LongPress(m) => Some(&mut m.mouse_state),
+            Self::MouseWheel(m) => Some(&mut m.mouse_state),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn mouse_state(&self) -> Option<&MouseState> {
+        match self {
+            Self::MouseInput(m) => Some(&m.mouse_state),
+            Self::CursorMoved(m) => Some(&m.mouse_state),
+            Self::CursorLeft(m) => Some(&m.mouse_state),
+            Self::MouseEnter(m) => Some(&m.mouse_state),
+            Self::MouseLeave(m) => Some(&m.mouse_state),
+            Self::LongPress(m) => Some(&m.mouse_state),
+            Self::MouseWheel(m) => Some(&m.mouse_state),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Phase {
+    Capture,
+    Target,
+    Bubble,
+}
+
+#[derive(Debug)]
+pub struct RoutedInput<'a> {
+    pub input: &'a Input,
+    pub target: AnyHandle,
+    pub phase: Phase,
+    stopped: bool,
+}
+
+impl<'a> RoutedInput<'a> {
+    #[inline]
+    pub(crate) fn new(input: &'a Input, target: AnyHandle) -> Self {
+        Self {
+            input,
+            target,
+            phase: Phase::Capture,
+            stopped: false,
+        }
+    }
+
+    #[inline]
+    pub fn stop_propagation(&mut self) {
+        self.stopped = true;
+    }
+
+    #[inline]
+    pub fn is_propagation_stopped(&self) -> bool {
+        self.stopped
+    }
 }
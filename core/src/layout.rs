@@ -1,4 +1,12 @@
 use super::*;
+use crate::layout_cache::{Subtree, SubtreeKey};
+use crate::spatial_index::{SpatialIndex, clip_rects};
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+fn shift(range: &Range<usize>, from: usize, to: usize) -> Range<usize> {
+    range.start - from + to..range.end - from + to
+}
 
 #[derive(Clone, Debug)]
 pub struct Area {
@@ -8,6 +16,12 @@ pub struct Area {
     pub ancestors: Vec<AnyHandle>,
     pub layer: u32,
     pub selected: bool,
+    pub fill: Option<Color>,
+    pub stroke: Option<Color>,
+    pub stroke_width: f32,
+    pub radius: f32,
+    pub shadow: Option<BoxShadow>,
+    pub opacity: f32,
 }
 
 #[derive(Clone, Debug)]
@@ -29,6 +43,8 @@ pub struct Text {
     pub string: String,
     pub layer: u32,
     pub selected: bool,
+    pub color: Option<Color>,
+    pub opacity: f32,
 }
 
 #[derive(Clone, Debug)]
@@ -53,6 +69,39 @@ pub struct Cursor {
     pub layer: u32,
 }
 
+#[derive(Clone, Debug)]
+pub struct Image {
+    pub handle: AnyHandle,
+    pub widget_state: WidgetState,
+    pub rect: LogicalRect<f32>,
+    pub ancestors: Vec<AnyHandle>,
+    pub source: ImageSource,
+    pub fit: ImageFit,
+    pub layer: u32,
+    pub opacity: f32,
+}
+
+impl Image {
+    #[inline]
+    pub fn image_rect(&self) -> LogicalRect<f32> {
+        self.fit.apply(self.source.size(), &self.rect)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Shape {
+    pub handle: AnyHandle,
+    pub widget_state: WidgetState,
+    pub rect: LogicalRect<f32>,
+    pub ancestors: Vec<AnyHandle>,
+    pub path: Path,
+    pub layer: u32,
+    pub fill: Option<Color>,
+    pub stroke: Option<Color>,
+    pub stroke_width: f32,
+    pub opacity: f32,
+}
+
 #[derive(Clone, Debug)]
 pub struct Clipping {
     pub handle: AnyHandle,
@@ -69,6 +118,8 @@ pub enum LayoutElement {
     Text(Text),
     CompositionText(CompositionText),
     Cursor(Cursor),
+    Image(Image),
+    Path(Shape),
     StartClipping(Clipping),
     EndClipping(Clipping),
 }
@@ -90,6 +141,12 @@ impl LayoutElement {
             ancestors: ancestors.to_vec(),
             layer,
             selected,
+            fill: None,
+            stroke: None,
+            stroke_width: 0.0,
+            radius: 0.0,
+            shadow: None,
+            opacity: 1.0,
         })
     }
 
@@ -131,6 +188,8 @@ impl LayoutElement {
             string,
             layer,
             selected,
+            color: None,
+            opacity: 1.0,
         })
     }
 
@@ -177,6 +236,51 @@ impl LayoutElement {
         })
     }
 
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub fn image(
+        widget: &impl Widget,
+        widget_state: WidgetState,
+        rect: LogicalRect<f32>,
+        ancestors: &[AnyHandle],
+        source: ImageSource,
+        fit: ImageFit,
+        layer: u32,
+    ) -> Self {
+        Self::Image(Image {
+            handle: AnyHandle::new(widget),
+            widget_state,
+            rect,
+            ancestors: ancestors.to_vec(),
+            source,
+            fit,
+            layer,
+            opacity: 1.0,
+        })
+    }
+
+    #[inline]
+    pub fn path(
+        widget: &impl Widget,
+        widget_state: WidgetState,
+        ancestors: &[AnyHandle],
+        path: Path,
+        layer: u32,
+    ) -> Self {
+        Self::Path(Shape {
+            handle: AnyHandle::new(widget),
+            widget_state,
+            rect: path.bounds(),
+            ancestors: ancestors.to_vec(),
+            path,
+            layer,
+            fill: None,
+            stroke: None,
+            stroke_width: 0.0,
+            opacity: 1.0,
+        })
+    }
+
     #[inline]
     pub fn start_clipping(
         widget: &impl Widget,
@@ -207,6 +311,87 @@ impl LayoutElement {
         })
     }
 
+    #[inline]
+    pub fn with_color(mut self, color: Option<Color>) -> Self {
+        match &mut self {
+            Self::Area(a) => a.fill = color,
+            Self::Text(t) => t.color = color,
+            Self::Path(p) => p.fill = color,
+            _ => {}
+        }
+        self
+    }
+
+    #[inline]
+    pub fn with_stroke(mut self, color: Option<Color>, width: f32) -> Self {
+        match &mut self {
+            Self::Area(a) => {
+                a.stroke = color;
+                a.stroke_width = width;
+            }
+            Self::Path(p) => {
+                p.stroke = color;
+                p.stroke_width = width;
+            }
+            _ => {}
+        }
+        self
+    }
+
+    #[inline]
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        if let Self::Area(a) = &mut self {
+            a.radius = radius;
+        }
+        self
+    }
+
+    #[inline]
+    pub fn with_shadow(mut self, shadow: Option<BoxShadow>) -> Self {
+        if let Self::Area(a) = &mut self {
+            a.shadow = shadow;
+        }
+        self
+    }
+
+    #[inline]
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        match &mut self {
+            Self::Area(a) => a.opacity = opacity,
+            Self::Text(t) => t.opacity = opacity,
+            Self::Image(i) => i.opacity = opacity,
+            Self::Path(p) => p.opacity = opacity,
+            _ => {}
+        }
+        self
+    }
+
+    pub fn with_style(self, style: &WidgetStyle, widget_state: WidgetState, focused: bool) -> Self {
+        match self {
+            Self::Area(_) => self
+                .with_color(style.background(widget_state, focused))
+                .with_stroke(
+                    style.border(widget_state, focused),
+                    style.border_width.unwrap_or(0.0),
+                )
+                .with_radius(style.corner_radius.unwrap_or(0.0))
+                .with_shadow(style.shadow)
+                .with_opacity(style.opacity.unwrap_or(1.0)),
+            Self::Text(_) => self
+                .with_color(style.foreground(widget_state, focused))
+                .with_opacity(style.opacity.unwrap_or(1.0)),
+            Self::Image(_) => self.with_opacity(style.opacity.unwrap_or(1.0)),
+            Self::Path(_) => self
+                .with_color(style.background(widget_state, focused))
+                .with_stroke(
+                    style.border(widget_state, focused),
+                    style.border_width.unwrap_or(0.0),
+                )
+                .with_opacity(style.opacity.unwrap_or(1.0)),
+            _ => self,
+        }
+    }
+
     #[inline]
     pub fn handle(&self) -> AnyHandle {
         match self {
@@ -215,11 +400,40 @@ impl LayoutElement {
             Self::Text(t) => t.handle,
             Self::CompositionText(t) => t.handle,
             Self::Cursor(c) => c.handle,
+            Self::Image(i) => i.handle,
+            Self::Path(p) => p.handle,
             Self::StartClipping(c) => c.handle,
             Self::EndClipping(c) => c.handle,
         }
     }
 
+    #[inline]
+    pub fn widget_state(&self) -> Option<WidgetState> {
+        match self {
+            Self::Area(a) => Some(a.widget_state),
+            Self::Collision(c) => Some(c.widget_state),
+            Self::Text(t) => Some(t.widget_state),
+            Self::CompositionText(t) => Some(t.widget_state),
+            Self::Cursor(c) => Some(c.widget_state),
+            Self::Image(i) => Some(i.widget_state),
+            Self::Path(p) => Some(p.widget_state),
+            _ => None,
+        }
+    }
+
+    fn set_widget_state(&mut self, widget_state: WidgetState) {
+        match self {
+            Self::Area(a) => a.widget_state = widget_state,
+            Self::Collision(c) => c.widget_state = widget_state,
+            Self::Text(t) => t.widget_state = widget_state,
+            Self::CompositionText(t) => t.widget_state = widget_state,
+            Self::Cursor(c) => c.widget_state = widget_state,
+            Self::Image(i) => i.widget_state = widget_state,
+            Self::Path(p) => p.widget_state = widget_state,
+            _ => {}
+        }
+    }
+
     #[inline]
     pub fn rect(&self) -> &LogicalRect<f32> {
         match self {
@@ -228,6 +442,8 @@ impl LayoutElement {
             Self::Text(t) => &t.rect,
             Self::CompositionText(t) => &t.rect,
             Self::Cursor(c) => &c.rect,
+            Self::Image(i) => &i.rect,
+            Self::Path(p) => &p.rect,
             Self::StartClipping(c) => &c.rect,
             Self::EndClipping(c) => &c.rect,
         }
@@ -241,6 +457,8 @@ impl LayoutElement {
             Self::Text(t) => &t.ancestors,
             Self::CompositionText(t) => &t.ancestors,
             Self::Cursor(c) => &c.ancestors,
+            Self::Image(i) => &i.ancestors,
+            Self::Path(p) => &p.ancestors,
             Self::StartClipping(c) => &c.ancestors,
             Self::EndClipping(c) => &c.ancestors,
         }
@@ -254,11 +472,23 @@ impl LayoutElement {
             Self::Text(t) => t.layer,
             Self::CompositionText(t) => t.layer,
             Self::Cursor(c) => c.layer,
+            Self::Image(i) => i.layer,
+            Self::Path(p) => p.layer,
             Self::StartClipping(c) => c.layer,
             Self::EndClipping(c) => c.layer,
         }
     }
 
+    #[inline]
+    pub fn color(&self) -> Option<Color> {
+        match self {
+            Self::Area(a) => a.fill,
+            Self::Text(t) => t.color,
+            Self::Path(p) => p.fill,
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn as_area(&self) -> Option<&Area> {
         match self {
@@ -299,6 +529,22 @@ impl LayoutElement {
         }
     }
 
+    #[inline]
+    pub fn as_image(&self) -> Option<&Image> {
+        match self {
+            Self::Image(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_path(&self) -> Option<&Shape> {
+        match self {
+            Self::Path(v) => Some(v),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn as_start_clipping(&self) -> Option<&Clipping> {
         match self {
@@ -323,6 +569,7 @@ pub struct LayoutContext<'a> {
     pub ancestors: Vec<AnyHandle>,
     pub layer: u32,
     pub selected: bool,
+    pub disabled: bool,
 }
 
 impl<'a> LayoutContext<'a> {
@@ -333,6 +580,33 @@ impl<'a> LayoutContext<'a> {
             ancestors: vec![],
             layer: 0,
             selected: false,
+            disabled: false,
+        }
+    }
+
+    #[inline]
+    pub fn is_disabled(&self, widget: &impl HasId) -> bool {
+        self.disabled || self.ctx.disabled.contains(&widget.id())
+    }
+
+    #[inline]
+    pub fn state(&self, widget: &impl HasId, widget_state: WidgetState) -> WidgetState {
+        if self.is_disabled(widget) {
+            WidgetState::Disabled
+        } else {
+            widget_state
+        }
+    }
+
+    #[inline]
+    pub fn with_rect(&self, rect: LogicalRect<f32>) -> Self {
+        Self {
+            ctx: self.ctx,
+            rect,
+            ancestors: self.ancestors.clone(),
+            layer: self.layer,
+            selected: self.selected,
+            disabled: self.disabled,
         }
     }
 
@@ -352,29 +626,132 @@ impl<'a> LayoutContext<'a> {
             ancestors,
             layer,
             selected,
+            disabled: self.is_disabled(widget),
         }
     }
+
+    #[inline]
+    pub fn child_size(&self, child: &dyn Widget) -> LogicalSize<f32> {
+        self.ctx
+            .layout_cache
+            .size(child.id(), self.rect.size(), || child.size(self))
+    }
+
+    #[inline]
+    pub fn child_size_hint(&self, child: &dyn Widget) -> SizeHint {
+        self.ctx
+            .layout_cache
+            .size_hint(child.id(), self.rect.size(), || child.size_hint(self))
+    }
+
+    #[inline]
+    pub fn measure_child(&self, child: &dyn Widget, constraints: Constraints) -> LogicalSize<f32> {
+        self.ctx
+            .layout_cache
+            .measure(child.id(), self.rect.size(), constraints, || {
+                child.measure(self, constraints)
+            })
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct LayoutConstructor {
     v: Vec<LayoutElement>,
+    focus_order: Vec<AnyHandle>,
+    subtrees: Vec<Subtree>,
+    pub(crate) reused: HashSet<Id>,
 }
 
 impl LayoutConstructor {
     #[inline]
     pub fn new() -> Self {
-        Self { v: vec![] }
+        Self {
+            v: vec![],
+            focus_order: vec![],
+            subtrees: vec![],
+            reused: HashSet::new(),
+        }
+    }
+
+    pub fn layout_child(&mut self, child: &dyn Widget, lc: LayoutContext) {
+        let id = child.id();
+        let key = SubtreeKey::new(&lc);
+        let elements = self.v.len();
+        let focus = self.focus_order.len();
+        let descendants = self.subtrees.len();
+        let prev = lc.ctx.layout.clone();
+        match prev.subtree(id) {
+            Some(old) if lc.ctx.layout_cache.is_clean(id) && old.key == key => {
+                self.reused.insert(id);
+                self.reused.extend(
+                    old.elements
+                        .clone()
+                        .map(|i| prev.v[prev.order[i]].handle().id()),
+                );
+                self.reused
+                    .extend(prev.subtrees[old.descendants.clone()].iter().map(|s| s.id));
+                self.v
+                    .extend(old.elements.clone().map(|i| prev.v[prev.order[i]].clone()));
+                self.focus_order
+                    .extend_from_slice(&prev.focus_order[old.focus.clone()]);
+                self.subtrees
+                    .extend(
+                        prev.subtrees[old.descendants.clone()]
+                            .iter()
+                            .map(|s| Subtree {
+                                id: s.id,
+                                key: s.key.clone(),
+                                elements: shift(&s.elements, old.elements.start, elements),
+                                focus: shift(&s.focus, old.focus.start, focus),
+                                descendants: shift(
+                                    &s.descendants,
+                                    old.descendants.start,
+                                    descendants,
+                                ),
+                            }),
+                    );
+            }
+            _ => child.layout(lc, self),
+        }
+        self.subtrees.push(Subtree {
+            id,
+            key,
+            elements: elements..self.v.len(),
+            focus: focus..self.focus_order.len(),
+            descendants: descendants..self.subtrees.len(),
+        });
     }
 
     #[inline]
-    pub fn push(&mut self, _ctx: &LayoutContext, element: LayoutElement) {
+    pub fn push(&mut self, lc: &LayoutContext, mut element: LayoutElement) {
+        if lc.disabled || lc.ctx.disabled.contains(&element.handle().id()) {
+            element.set_widget_state(WidgetState::Disabled);
+        }
         self.v.push(element);
     }
 
+    #[inline]
+    pub fn push_focusable(&mut self, lc: &LayoutContext, widget: &impl Widget) {
+        if !lc.is_disabled(widget) {
+            self.focus_order.push(AnyHandle::new(widget));
+        }
+    }
+
     #[inline]
     pub fn append(&mut self, mut other: Self) {
+        let elements = self.v.len();
+        let focus = self.focus_order.len();
+        let descendants = self.subtrees.len();
+        self.subtrees
+            .extend(other.subtrees.into_iter().map(|s| Subtree {
+                elements: shift(&s.elements, 0, elements),
+                focus: shift(&s.focus, 0, focus),
+                descendants: shift(&s.descendants, 0, descendants),
+                ..s
+            }));
         self.v.append(&mut other.v);
+        self.focus_order.append(&mut other.focus_order);
+        self.reused.extend(other.reused);
     }
 
     #[inline]
@@ -382,7 +759,11 @@ impl LayoutConstructor {
     where
         F: FnMut(&LayoutElement) -> bool,
     {
+        let len = self.v.len();
         self.v.retain(f);
+        if self.v.len() != len {
+            self.subtrees.clear();
+        }
     }
 
     #[inline]
@@ -425,20 +806,103 @@ impl LayoutConstructor {
 #[derive(Clone, Debug)]
 pub struct Layout {
     v: Vec<LayoutElement>,
+    order: Vec<usize>,
+    focus_order: Vec<AnyHandle>,
+    subtrees: Vec<Subtree>,
+    subtree_ids: HashMap<Id, usize>,
+    ids: HashMap<Id, Vec<usize>>,
+    spatial_index: SpatialIndex,
 }
 
 impl Layout {
     pub(crate) fn empty() -> Self {
-        Self { v: vec![] }
+        Self {
+            v: vec![],
+            order: vec![],
+            focus_order: vec![],
+            subtrees: vec![],
+            subtree_ids: HashMap::new(),
+            ids: HashMap::new(),
+            spatial_index: SpatialIndex::default(),
+        }
     }
 
-    pub(crate) fn new(_ctx: &Context, mut c: LayoutConstructor) -> Self {
-        c.v.sort_by_key(|a| a.layer());
-        Self { v: c.v }
+    pub(crate) fn new(_ctx: &Context, c: LayoutConstructor) -> Self {
+        let clips = clip_rects(&c.v);
+        let mut elements = c.v.into_iter().zip(clips).enumerate().collect::<Vec<_>>();
+        elements.sort_by_key(|(_, (e, _))| e.layer());
+        let mut order = vec![0; elements.len()];
+        for (i, (original, _)) in elements.iter().enumerate() {
+            order[*original] = i;
+        }
+        let (v, clips): (Vec<_>, Vec<_>) = elements.into_iter().map(|(_, e)| e).unzip();
+        let mut ids: HashMap<Id, Vec<usize>> = HashMap::new();
+        for (i, e) in v.iter().enumerate() {
+            ids.entry(e.handle().id()).or_default().push(i);
+        }
+        let subtree_ids = c
+            .subtrees
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.id, i))
+            .collect();
+        Self {
+            v,
+            order,
+            focus_order: c.focus_order,
+            subtrees: c.subtrees,
+            subtree_ids,
+            ids,
+            spatial_index: SpatialIndex::new(clips),
+        }
+    }
+
+    fn subtree(&self, id: Id) -> Option<&Subtree> {
+        self.subtree_ids.get(&id).map(|i| &self.subtrees[*i])
+    }
+
+    #[inline]
+    pub fn find(&self, id: Id) -> impl Iterator<Item = &LayoutElement> + '_ {
+        self.ids
+            .get(&id)
+            .into_iter()
+            .flat_map(|indices| indices.iter().map(|i| &self.v[*i]))
+    }
+
+    pub fn hit_test(&self, position: LogicalPosition<f32>) -> Vec<AnyHandle> {
+        let mut hits = self.spatial_index.query(position).collect::<Vec<_>>();
+        hits.sort_by_key(|i| std::cmp::Reverse(self.v[*i].layer()));
+        let mut result: Vec<AnyHandle> = vec![];
+        for i in hits {
+            let handle = self.v[i].handle();
+            if !result.contains(&handle) {
+                result.push(handle);
+            }
+        }
+        result
+    }
+
+    pub(crate) fn ancestors_of(&self, id: Id) -> Option<&[AnyHandle]> {
+        if let Some(subtree) = self.subtree(id) {
+            return Some(&subtree.key.ancestors);
+        }
+        self.v.iter().find_map(|e| {
+            if e.handle().id() == id {
+                return Some(e.ancestors());
+            }
+            let ancestors = e.ancestors();
+            let i = ancestors.iter().position(|a| a.id() == id)?;
+            Some(&ancestors[..i])
+        })
+    }
+
+    #[inline]
+    pub fn focus_order(&self) -> &[AnyHandle] {
+        &self.focus_order
     }
 
     #[inline]
-    pub fn iter(&self) -> impl ExactSizeIterator<Item = &LayoutElement> {
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &LayoutElement> + DoubleEndedIterator {
         self.v.iter()
     }
 
@@ -0,0 +1,166 @@
+use super::*;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum SizeKind {
+    Size,
+    SizeHint,
+    Measure([u32; 4]),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct SizeKey {
+    id: Id,
+    kind: SizeKind,
+    available: [u32; 2],
+}
+
+impl SizeKey {
+    fn new(id: Id, kind: SizeKind, available: LogicalSize<f32>) -> Self {
+        Self {
+            id,
+            kind,
+            available: [available.width.to_bits(), available.height.to_bits()],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Cached {
+    Size(LogicalSize<f32>),
+    SizeHint(SizeHint),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct SubtreeKey {
+    pub rect: LogicalRect<f32>,
+    pub ancestors: Vec<AnyHandle>,
+    pub layer: u32,
+    pub selected: bool,
+    pub disabled: bool,
+}
+
+impl SubtreeKey {
+    pub fn new(lc: &LayoutContext) -> Self {
+        Self {
+            rect: lc.rect,
+            ancestors: lc.ancestors.clone(),
+            layer: lc.layer,
+            selected: lc.selected,
+            disabled: lc.disabled,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Subtree {
+    pub id: Id,
+    pub key: SubtreeKey,
+    pub elements: Range<usize>,
+    pub focus: Range<usize>,
+    pub descendants: Range<usize>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct LayoutCache {
+    sizes: RefCell<HashMap<SizeKey, Cached>>,
+    invalidated: RefCell<HashSet<Id>>,
+    dirty: HashSet<Id>,
+    all: bool,
+}
+
+impl LayoutCache {
+    pub fn invalidate(&self, id: Id) {
+        self.invalidated.borrow_mut().insert(id);
+    }
+
+    pub fn invalidate_all(&mut self) {
+        self.all = true;
+    }
+
+    pub fn begin(&mut self, prev: &Layout) {
+        let invalidated = std::mem::take(self.invalidated.get_mut());
+        self.dirty.clear();
+        if !self.all {
+            for id in invalidated {
+                let Some(ancestors) = prev.ancestors_of(id) else {
+                    self.all = true;
+                    break;
+                };
+                self.dirty.insert(id);
+                self.dirty.extend(ancestors.iter().map(|a| a.id()));
+            }
+        }
+        if self.all {
+            self.sizes.get_mut().clear();
+        } else {
+            let dirty = &self.dirty;
+            self.sizes
+                .get_mut()
+                .retain(|key, _| !dirty.contains(&key.id));
+        }
+    }
+
+    pub fn end(&mut self) {
+        self.all = false;
+        self.dirty.clear();
+    }
+
+    pub fn is_clean(&self, id: Id) -> bool {
+        !self.all && !self.dirty.contains(&id)
+    }
+
+    pub fn size(
+        &self,
+        id: Id,
+        available: LogicalSize<f32>,
+        f: impl FnOnce() -> LogicalSize<f32>,
+    ) -> LogicalSize<f32> {
+        let key = SizeKey::new(id, SizeKind::Size, available);
+        if let Some(Cached::Size(size)) = self.sizes.borrow().get(&key) {
+            return *size;
+        }
+        let size = f();
+        self.sizes.borrow_mut().insert(key, Cached::Size(size));
+        size
+    }
+
+    pub fn size_hint(
+        &self,
+        id: Id,
+        available: LogicalSize<f32>,
+        f: impl FnOnce() -> SizeHint,
+    ) -> SizeHint {
+        let key = SizeKey::new(id, SizeKind::SizeHint, available);
+        if let Some(Cached::SizeHint(hint)) = self.sizes.borrow().get(&key) {
+            return *hint;
+        }
+        let hint = f();
+        self.sizes.borrow_mut().insert(key, Cached::SizeHint(hint));
+        hint
+    }
+
+    pub fn measure(
+        &self,
+        id: Id,
+        available: LogicalSize<f32>,
+        constraints: Constraints,
+        f: impl FnOnce() -> LogicalSize<f32>,
+    ) -> LogicalSize<f32> {
+        let kind = SizeKind::Measure([
+            constraints.min.width.to_bits(),
+            constraints.min.height.to_bits(),
+            constraints.max.width.to_bits(),
+            constraints.max.height.to_bits(),
+        ]);
+        let key = SizeKey::new(id, kind, available);
+        if let Some(Cached::Size(size)) = self.sizes.borrow().get(&key) {
+            return *size;
+        }
+        let size = f();
+        self.sizes.borrow_mut().insert(key, Cached::Size(size));
+        size
+    }
+}
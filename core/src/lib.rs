@@ -1,16 +1,36 @@
+mod animation;
+mod click;
+mod constraints;
+mod drag;
 mod event;
 mod font;
 mod id;
+mod image;
 pub mod input;
 pub mod layout;
+mod layout_cache;
+mod path;
 mod scene;
+mod shortcut;
+mod spatial_index;
+mod theme;
+mod tooltip;
+mod touch;
 mod widget;
 
+pub use animation::{Easing, FRAME_INTERVAL, Interpolate, Transition};
+pub use constraints::*;
+pub use drag::{DragEvent, DragFeedback, DragKind, DragPreview, StartDrag};
 pub use event::*;
 pub use font::*;
 pub use gedv::*;
 pub use id::*;
+pub use image::*;
 pub use input::*;
 pub use layout::{Layout, LayoutConstructor, LayoutContext, LayoutElement};
+pub use path::*;
 pub use scene::*;
+pub use shortcut::*;
+pub use theme::*;
+pub use tooltip::*;
 pub use widget::*;
@@ -0,0 +1,162 @@
+use super::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum PathCommand {
+    MoveTo(LogicalPosition<f32>),
+    LineTo(LogicalPosition<f32>),
+    QuadTo(LogicalPosition<f32>, LogicalPosition<f32>),
+    CubicTo(
+        LogicalPosition<f32>,
+        LogicalPosition<f32>,
+        LogicalPosition<f32>,
+    ),
+    Close,
+}
+
+impl PathCommand {
+    fn map(self, f: impl Fn(LogicalPosition<f32>) -> LogicalPosition<f32>) -> Self {
+        match self {
+            Self::MoveTo(p) => Self::MoveTo(f(p)),
+            Self::LineTo(p) => Self::LineTo(f(p)),
+            Self::QuadTo(c, p) => Self::QuadTo(f(c), f(p)),
+            Self::CubicTo(c0, c1, p) => Self::CubicTo(f(c0), f(c1), f(p)),
+            Self::Close => Self::Close,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct Path {
+    commands: Vec<PathCommand>,
+}
+
+impl Path {
+    #[inline]
+    pub fn new() -> Self {
+        Self { commands: vec![] }
+    }
+
+    #[inline]
+    pub fn move_to(mut self, p: impl Into<LogicalPosition<f32>>) -> Self {
+        self.commands.push(PathCommand::MoveTo(p.into()));
+        self
+    }
+
+    #[inline]
+    pub fn line_to(mut self, p: impl Into<LogicalPosition<f32>>) -> Self {
+        self.commands.push(PathCommand::LineTo(p.into()));
+        self
+    }
+
+    #[inline]
+    pub fn quad_to(
+        mut self,
+        ctrl: impl Into<LogicalPosition<f32>>,
+        p: impl Into<LogicalPosition<f32>>,
+    ) -> Self {
+        self.commands
+            .push(PathCommand::QuadTo(ctrl.into(), p.into()));
+        self
+    }
+
+    #[inline]
+    pub fn cubic_to(
+        mut self,
+        c0: impl Into<LogicalPosition<f32>>,
+        c1: impl Into<LogicalPosition<f32>>,
+        p: impl Into<LogicalPosition<f32>>,
+    ) -> Self {
+        self.commands
+            .push(PathCommand::CubicTo(c0.into(), c1.into(), p.into()));
+        self
+    }
+
+    #[inline]
+    pub fn close(mut self) -> Self {
+        self.commands.push(PathCommand::Close);
+        self
+    }
+
+    #[inline]
+    pub fn commands(&self) -> &[PathCommand] {
+        &self.commands
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    pub fn translate(self, dx: f32, dy: f32) -> Self {
+        self.map(|p| LogicalPosition::new(p.x + dx, p.y + dy))
+    }
+
+    pub fn scale(self, sx: f32, sy: f32) -> Self {
+        self.map(|p| LogicalPosition::new(p.x * sx, p.y * sy))
+    }
+
+    pub fn map(self, f: impl Fn(LogicalPosition<f32>) -> LogicalPosition<f32>) -> Self {
+        Self {
+            commands: self.commands.into_iter().map(|c| c.map(&f)).collect(),
+        }
+    }
+
+    pub fn bounds(&self) -> LogicalRect<f32> {
+        let mut points = self.commands.iter().flat_map(|c| match *c {
+            PathCommand::MoveTo(p) | PathCommand::LineTo(p) => vec![p],
+            PathCommand::QuadTo(c, p) => vec![c, p],
+            PathCommand::CubicTo(c0, c1, p) => vec![c0, c1, p],
+            PathCommand::Close => vec![],
+        });
+        let Some(first) = points.next() else {
+            return LogicalRect::new(0.0, 0.0, 0.0, 0.0);
+        };
+        points.fold(
+            LogicalRect::new(first.x, first.y, first.x, first.y),
+            |r, p| {
+                LogicalRect::new(
+                    r.left.min(p.x),
+                    r.top.min(p.y),
+                    r.right.max(p.x),
+                    r.bottom.max(p.y),
+                )
+            },
+        )
+    }
+
+    pub fn check_mark(rect: &LogicalRect<f32>) -> Self {
+        let size = rect.size();
+        Self::new()
+            .move_to((rect.left + size.width * 0.2, rect.top + size.height * 0.5))
+            .line_to((rect.left + size.width * 0.42, rect.top + size.height * 0.72))
+            .line_to((rect.left + size.width * 0.8, rect.top + size.height * 0.28))
+    }
+
+    pub fn chevron_up(rect: &LogicalRect<f32>) -> Self {
+        Self::chevron(rect, |x, y| (x, 1.0 - y))
+    }
+
+    pub fn chevron_down(rect: &LogicalRect<f32>) -> Self {
+        Self::chevron(rect, |x, y| (x, y))
+    }
+
+    pub fn chevron_left(rect: &LogicalRect<f32>) -> Self {
+        Self::chevron(rect, |x, y| (1.0 - y, x))
+    }
+
+    pub fn chevron_right(rect: &LogicalRect<f32>) -> Self {
+        Self::chevron(rect, |x, y| (y, x))
+    }
+
+    fn chevron(rect: &LogicalRect<f32>, f: impl Fn(f32, f32) -> (f32, f32)) -> Self {
+        let size = rect.size();
+        let point = |x: f32, y: f32| {
+            let (x, y) = f(x, y);
+            LogicalPosition::new(rect.left + size.width * x, rect.top + size.height * y)
+        };
+        Self::new()
+            .move_to(point(0.25, 0.375))
+            .line_to(point(0.5, 0.625))
+            .line_to(point(0.75, 0.375))
+    }
+}
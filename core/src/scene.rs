@@ -1,6 +1,14 @@
 use super::*;
+use crate::animation::{Animations, FRAME_INTERVAL};
+use crate::click::Clicks;
+use crate::drag::{Drag, DragPreviews};
+use crate::layout_cache::LayoutCache;
+use crate::touch::Touches;
 use std::any::Any;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 #[derive(Debug)]
 pub struct Context {
@@ -9,7 +17,15 @@ pub struct Context {
     pub default_font: Option<Font>,
     pub prev_input: Option<Input>,
     focus: Option<AnyHandle>,
+    pointer_capture: Option<AnyHandle>,
+    now: Instant,
+    deadlines: RefCell<Vec<(Id, Instant)>>,
+    animations: Animations,
+    theme: Theme,
+    styles: RefCell<HashMap<(Id, &'static str), Arc<WidgetStyle>>>,
+    pub(crate) disabled: HashSet<Id>,
     pub(crate) bounding_box_cache: Arc<BoundingBoxCache>,
+    pub(crate) layout_cache: LayoutCache,
 }
 
 impl Context {
@@ -19,13 +35,153 @@ impl Context {
         widget: &dyn Widget,
     ) -> impl Iterator<Item = &'a LayoutElement> + use<'a> {
         let id = widget.id();
-        self.layout.iter().filter(move |l| l.handle().id() == id)
+        self.layout.find(id)
     }
 
     #[inline]
     pub fn has_focus<T: Widget>(&self, widget: &T) -> bool {
         self.focus.is_some_and(|focus| focus == Handle::new(widget))
     }
+
+    #[inline]
+    pub fn pointer_capture(&self) -> Option<AnyHandle> {
+        self.pointer_capture
+    }
+
+    #[inline]
+    pub fn has_pointer_capture(&self, widget: &impl HasId) -> bool {
+        self.pointer_capture
+            .is_some_and(|capture| capture.id() == widget.id())
+    }
+
+    #[inline]
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    pub fn style(&self, widget: &impl HasId, class: &'static str) -> Arc<WidgetStyle> {
+        self.styles
+            .borrow_mut()
+            .entry((widget.id(), class))
+            .or_insert_with(|| Arc::new(self.theme.resolve(widget, class)))
+            .clone()
+    }
+
+    pub fn is_disabled(&self, widget: &impl HasId) -> bool {
+        if self.disabled.is_empty() {
+            return false;
+        }
+        let id = widget.id();
+        self.disabled.contains(&id)
+            || self.layout.find(id).any(|l| {
+                l.ancestors()
+                    .iter()
+                    .any(|a| self.disabled.contains(&a.id()))
+            })
+    }
+
+    #[inline]
+    pub fn is_enabled(&self, widget: &impl HasId) -> bool {
+        !self.is_disabled(widget)
+    }
+
+    #[inline]
+    pub fn invalidate(&self, widget: &impl HasId) {
+        self.layout_cache.invalidate(widget.id());
+    }
+
+    #[inline]
+    pub fn now(&self) -> Instant {
+        self.now
+    }
+
+    #[inline]
+    pub fn request_wake_up(&self, widget: &impl HasId, deadline: Instant) {
+        let mut deadlines = self.deadlines.borrow_mut();
+        let entry = (widget.id(), deadline);
+        if !deadlines.contains(&entry) {
+            deadlines.push(entry);
+        }
+    }
+
+    #[inline]
+    pub fn animate<T, V>(
+        &self,
+        widget: &T,
+        property: &'static str,
+        target: V,
+        transition: &Transition,
+    ) -> V
+    where
+        T: Widget,
+        V: Interpolate,
+    {
+        let value = self.animations.animate(
+            AnyHandle::new(widget),
+            property,
+            target,
+            transition,
+            self.now,
+        );
+        if self
+            .animations
+            .is_running::<V>(AnyHandle::new(widget), property, self.now)
+        {
+            self.request_wake_up(widget, self.now + FRAME_INTERVAL);
+        }
+        value
+    }
+
+    pub fn animate_style<T>(
+        &self,
+        widget: &T,
+        style: &WidgetStyle,
+        widget_state: WidgetState,
+        focused: bool,
+    ) -> WidgetStyle
+    where
+        T: Widget,
+    {
+        let transition = Transition::default();
+        let animate = |property, colors: Option<StateColors>| {
+            colors.map(|colors| {
+                StateColors::new(self.animate(
+                    widget,
+                    property,
+                    colors.get(widget_state, focused),
+                    &transition,
+                ))
+            })
+        };
+        let mut animated = style.clone();
+        animated.background = animate("background", style.background);
+        animated.foreground = animate("foreground", style.foreground);
+        animated.border = animate("border", style.border);
+        animated
+    }
+
+    #[inline]
+    pub fn is_animating<T, V>(&self, widget: &T, property: &'static str) -> bool
+    where
+        T: Widget,
+        V: Interpolate,
+    {
+        self.animations
+            .is_running::<V>(AnyHandle::new(widget), property, self.now)
+    }
+
+    fn update_time(&mut self, now: Instant) {
+        self.now = self.now.max(now);
+        let now = self.now;
+        let layout_cache = &mut self.layout_cache;
+        self.deadlines.get_mut().retain(|(id, deadline)| {
+            if *deadline > now {
+                return true;
+            }
+            layout_cache.invalidate(*id);
+            false
+        });
+    }
 }
 
 #[allow(clippy::type_complexity)]
@@ -34,11 +190,97 @@ struct ApplyElement {
     f: Option<Box<dyn FnOnce(&mut dyn Any)>>,
 }
 
-pub struct ApplyFuncs(Vec<ApplyElement>);
+enum Delivery<'a> {
+    Input(&'a Input),
+    Routed(RoutedInput<'a>),
+    Drag(DragEvent),
+}
+
+struct Dispatch<'a> {
+    ctx: &'a Context,
+    delivery: Delivery<'a>,
+    events: Events,
+    ids: Vec<Id>,
+    bubble: bool,
+    stopped: bool,
+}
+
+impl Dispatch<'_> {
+    fn visit<T>(&mut self, widget: &mut T)
+    where
+        T: Widget,
+    {
+        let id = widget.id();
+        if !self.ids.contains(&id) {
+            return;
+        }
+        match &mut self.delivery {
+            Delivery::Input(input) => {
+                self.stopped =
+                    widget.input(self.ctx, input, &mut self.events) == ControlFlow::Break;
+            }
+            Delivery::Routed(input) => {
+                if input.is_propagation_stopped() {
+                    return;
+                }
+                input.phase = if self.bubble {
+                    Phase::Bubble
+                } else if id == input.target.id() {
+                    Phase::Target
+                } else {
+                    Phase::Capture
+                };
+                widget.on_input(self.ctx, input, &mut self.events);
+                self.stopped = input.is_propagation_stopped();
+            }
+            Delivery::Drag(drag) => {
+                widget.on_drag(self.ctx, drag, &mut self.events);
+                let feedback = match drag.kind {
+                    DragKind::Enter if drag.is_accepted() => Some(DragFeedback::Entered),
+                    DragKind::Leave => Some(DragFeedback::Left),
+                    DragKind::Drop => Some(DragFeedback::Dropped),
+                    DragKind::End { dropped: false } => Some(DragFeedback::Cancelled),
+                    _ => None,
+                };
+                if let Some(feedback) = feedback {
+                    self.events.push(widget, feedback);
+                }
+            }
+        }
+    }
+}
+
+pub struct ApplyFuncs<'a> {
+    funcs: Vec<ApplyElement>,
+    dispatch: Option<Dispatch<'a>>,
+}
 
-impl ApplyFuncs {
+impl<'a> ApplyFuncs<'a> {
     pub(crate) fn new() -> Self {
-        Self(vec![])
+        Self {
+            funcs: vec![],
+            dispatch: None,
+        }
+    }
+
+    fn dispatch(
+        ctx: &'a Context,
+        delivery: Delivery<'a>,
+        events: Events,
+        ids: Vec<Id>,
+        bubble: bool,
+    ) -> Self {
+        Self {
+            funcs: vec![],
+            dispatch: Some(Dispatch {
+                ctx,
+                delivery,
+                events,
+                ids,
+                bubble,
+                stopped: false,
+            }),
+        }
     }
 
     #[inline]
@@ -48,7 +290,7 @@ impl ApplyFuncs {
         F: FnOnce(&mut T) + 'static,
     {
         let handle = handle.clone();
-        self.0.push(ApplyElement {
+        self.funcs.push(ApplyElement {
             handle: handle.into(),
             f: Some(Box::new(|widget| f(widget.downcast_mut::<T>().unwrap()))),
         });
@@ -58,8 +300,11 @@ impl ApplyFuncs {
     where
         T: Widget,
     {
+        if let Some(dispatch) = self.dispatch.as_mut() {
+            dispatch.visit(widget);
+        }
         let id = widget.id();
-        self.0
+        self.funcs
             .iter_mut()
             .filter(move |elem| elem.handle.id() == id)
             .for_each(|elem| {
@@ -73,7 +318,17 @@ pub struct Scene {
     ctx: Context,
     root: Box<dyn Widget>,
     prev_input: Option<Input>,
-    apply_funcs: ApplyFuncs,
+    apply_funcs: ApplyFuncs<'static>,
+    tooltips: Tooltips,
+    modifiers: Modifiers,
+    pressed_keys: Vec<VirtualKey>,
+    mouse_state: Option<MouseState>,
+    capture_button: MouseButton,
+    drag: Option<Drag>,
+    drag_previews: DragPreviews,
+    clicks: Clicks,
+    hover: Vec<AnyHandle>,
+    touches: Touches,
 }
 
 impl Scene {
@@ -86,16 +341,34 @@ impl Scene {
                 ctx: Context {
                     viewport: LogicalSize::new(1024.0, 768.0),
                     focus: None,
+                    pointer_capture: None,
                     layout: Arc::new(Layout::empty()),
                     default_font: FontFace::from_os_default()
                         .ok()
                         .map(|face| Font::new(&face, 14.0)),
                     prev_input: None,
+                    now: Instant::now(),
+                    deadlines: RefCell::new(vec![]),
+                    animations: Animations::default(),
+                    theme: Theme::default(),
+                    styles: RefCell::new(HashMap::new()),
+                    disabled: HashSet::new(),
                     bounding_box_cache: BoundingBoxCache::new(256),
+                    layout_cache: LayoutCache::default(),
                 },
                 root,
                 prev_input: None,
                 apply_funcs: ApplyFuncs::new(),
+                tooltips: Tooltips::new(),
+                modifiers: Modifiers::NONE,
+                pressed_keys: vec![],
+                mouse_state: None,
+                capture_button: MouseButton::Left,
+                drag: None,
+                drag_previews: DragPreviews::new(),
+                clicks: Clicks::new(),
+                hover: vec![],
+                touches: Touches::new(),
             },
             handle,
         )
@@ -103,6 +376,9 @@ impl Scene {
 
     #[inline]
     pub fn set_viewport(&mut self, size: LogicalSize<f32>) {
+        if self.ctx.viewport != size {
+            self.ctx.layout_cache.invalidate_all();
+        }
         self.ctx.viewport = size;
     }
 
@@ -111,22 +387,466 @@ impl Scene {
         self.ctx.default_font.as_ref()
     }
 
+    #[inline]
     pub fn input(&mut self, input: Input, events: &mut Events) {
-        if !self.apply_funcs.0.is_empty() {
-            self.root.apply(&mut self.apply_funcs);
-            self.apply_funcs.0.clear();
+        self.input_at(input, Instant::now(), events);
+    }
+
+    pub fn input_at(&mut self, mut input: Input, timestamp: Instant, events: &mut Events) {
+        self.ctx.update_time(timestamp);
+        self.flush_apply_funcs();
+        self.update_keys(&mut input);
+        if let Some(long_press) = self.clicks.long_press(self.ctx.now) {
+            self.dispatch_input(Input::LongPress(long_press), events);
+        }
+        match &mut input {
+            Input::MouseInput(m) => self.clicks.mouse_input(m, self.ctx.now),
+            Input::CursorMoved(m) => self.clicks.cursor_moved(&m.mouse_state.position),
+            Input::CursorLeft(_) | Input::FocusLost => self.clicks.cancel(),
+            _ => {}
+        }
+        if let Some(mouse_state) = input.mouse_state() {
+            self.mouse_state = Some(mouse_state.clone());
         }
+        if self.drag.is_some() && self.drag_input(&input, events) {
+            self.prev_input = Some(input);
+            return;
+        }
+        if self.ctx.pointer_capture.is_some()
+            && matches!(input, Input::CursorLeft(_) | Input::FocusLost)
+        {
+            if let Some(mouse_state) = self.mouse_state.clone() {
+                let release = Input::MouseInput(MouseInput {
+                    button: self.capture_button,
+                    button_state: ButtonState::Released,
+                    click_count: 1,
+                    mouse_state,
+                });
+                self.dispatch_input(release, events);
+            }
+            self.ctx.pointer_capture = None;
+        }
+        let pointer = matches!(input, Input::Touch(_) | Input::Pen(_)).then(|| input.clone());
+        let captured = self.ctx.pointer_capture.is_some();
+        let handled = self.dispatch_input(input, events);
+        let emulated = match pointer {
+            Some(Input::Touch(touch)) => {
+                self.touches.touch(&touch, handled, captured, self.ctx.now)
+            }
+            Some(Input::Pen(pen)) => self.touches.pen(&pen, handled),
+            _ => vec![],
+        };
+        for input in emulated {
+            self.input_at(input, timestamp, events);
+        }
+    }
+
+    fn dispatch_input(&mut self, input: Input, events: &mut Events) -> bool {
         self.ctx.prev_input = self.prev_input.take();
-        self.root.input(&self.ctx, &input, events);
-        if let Input::MouseInput(m) = &input {
-            if m.button_state == ButtonState::Pressed {
-                self.ctx.focus = events
+        let len = events.len();
+        let capture = input.position().and(self.ctx.pointer_capture);
+        let stopped = match capture {
+            Some(target) => self.deliver(&input, target, events),
+            None => {
+                let routed = self
+                    .input_target(&input)
+                    .is_some_and(|target| self.route(&input, target, events));
+                routed || self.root.input(&self.ctx, &input, events) == ControlFlow::Break
+            }
+        };
+        self.update_pointer_capture(&input, events, len);
+        self.start_drag(events, len);
+        match &input {
+            Input::CursorMoved(m) => self.update_hover(Some(m.mouse_state.position), events),
+            Input::CursorLeft(_) => self.update_hover(None, events),
+            _ => {}
+        }
+        let emitted = events.iter().skip(len).map(|event| event.handle());
+        for handle in emitted.chain(capture).chain(self.ctx.pointer_capture) {
+            self.ctx.layout_cache.invalidate(handle.id());
+        }
+        self.tooltips.input(&self.ctx, &input);
+        match &input {
+            Input::MouseInput(m) if m.button_state == ButtonState::Pressed => {
+                let focus = events
                     .iter()
                     .find(|event| event.is_set_focus())
                     .map(|event| event.handle());
+                self.change_focus(focus);
             }
+            Input::KeyInput(k)
+                if k.vkey == VirtualKey::Tab
+                    && k.key_state == KeyState::Pressed
+                    && !events.iter().any(|event| event.is_set_focus()) =>
+            {
+                self.move_focus(!k.modifiers.shift);
+            }
+            _ => {}
         }
         self.prev_input = Some(input);
+        stopped
+    }
+
+    fn update_keys(&mut self, input: &mut Input) {
+        match input {
+            Input::KeyInput(k) => {
+                let held = self.pressed_keys.contains(&k.vkey);
+                match k.key_state {
+                    KeyState::Pressed if held => k.repeat = true,
+                    KeyState::Pressed => self.pressed_keys.push(k.vkey),
+                    KeyState::Released => self.pressed_keys.retain(|vkey| *vkey != k.vkey),
+                }
+                self.modifiers =
+                    self.pressed_keys
+                        .iter()
+                        .fold(Modifiers::NONE, |modifiers, vkey| match vkey {
+                            VirtualKey::Shift => modifiers | Modifiers::SHIFT,
+                            VirtualKey::Ctrl => modifiers | Modifiers::CTRL,
+                            VirtualKey::Alt => modifiers | Modifiers::ALT,
+                            &LEFT_META | &RIGHT_META => modifiers | Modifiers::META,
+                            _ => modifiers,
+                        });
+                k.modifiers |= self.modifiers;
+            }
+            Input::FocusLost => {
+                self.pressed_keys.clear();
+                self.modifiers = Modifiers::NONE;
+            }
+            _ => {
+                if let Some(mouse_state) = input.mouse_state_mut() {
+                    mouse_state.modifiers |= self.modifiers;
+                }
+            }
+        }
+    }
+
+    fn flush_apply_funcs(&mut self) {
+        if self.apply_funcs.funcs.is_empty() {
+            return;
+        }
+        for elem in self.apply_funcs.funcs.iter() {
+            self.ctx.layout_cache.invalidate(elem.handle.id());
+        }
+        self.root.apply(&mut self.apply_funcs);
+        self.apply_funcs.funcs.clear();
+    }
+
+    fn update_pointer_capture(&mut self, input: &Input, events: &Events, len: usize) {
+        if let Input::MouseInput(m) = input
+            && m.button_state == ButtonState::Released
+            && m.button == self.capture_button
+        {
+            self.ctx.pointer_capture = None;
+        }
+        for event in events.iter().skip(len) {
+            if event.is_capture_pointer() {
+                self.ctx.pointer_capture = Some(event.handle());
+                if let Input::MouseInput(m) = input {
+                    self.capture_button = m.button;
+                }
+            } else if event.is_release_pointer() && self.ctx.pointer_capture == Some(event.handle())
+            {
+                self.ctx.pointer_capture = None;
+            }
+        }
+    }
+
+    fn update_hover(&mut self, position: Option<LogicalPosition<f32>>, events: &mut Events) {
+        let Some(mouse_state) = self.mouse_state.clone() else {
+            return;
+        };
+        let mut path = vec![];
+        if let Some(hit) = position.and_then(|p| self.hit(p)) {
+            if let Some(ancestors) = self.ctx.layout.ancestors_of(hit.id()) {
+                path.extend(ancestors.iter().copied());
+            }
+            path.push(hit);
+        }
+        let prev = std::mem::replace(&mut self.hover, path.clone());
+        for handle in prev.iter().rev().filter(|h| !path.contains(h)) {
+            let input = Input::MouseLeave(CursorLeft {
+                mouse_state: mouse_state.clone(),
+            });
+            self.deliver(&input, *handle, events);
+        }
+        for handle in path.iter().filter(|h| !prev.contains(h)) {
+            let input = Input::MouseEnter(CursorMoved {
+                mouse_state: mouse_state.clone(),
+            });
+            self.deliver(&input, *handle, events);
+        }
+    }
+
+    fn hit(&self, position: LogicalPosition<f32>) -> Option<AnyHandle> {
+        let overlays = [self.tooltips.id(), self.drag_previews.id()];
+        self.ctx
+            .layout
+            .hit_test(position)
+            .into_iter()
+            .find(|handle| !overlays.contains(&handle.id()))
+    }
+
+    fn deliver(&mut self, input: &Input, target: AnyHandle, events: &mut Events) -> bool {
+        let mut funcs = ApplyFuncs::dispatch(
+            &self.ctx,
+            Delivery::Input(input),
+            std::mem::take(events),
+            vec![target.id()],
+            false,
+        );
+        self.root.apply(&mut funcs);
+        let dispatch = funcs.dispatch.unwrap();
+        *events = dispatch.events;
+        dispatch.stopped
+    }
+
+    fn start_drag(&mut self, events: &mut Events, len: usize) {
+        let position = self
+            .mouse_state
+            .as_ref()
+            .map(|m| m.position)
+            .unwrap_or(LogicalPosition::new(0.0, 0.0));
+        for event in events.iter_mut().skip(len) {
+            let source = event.handle();
+            if let Some(start) = event.downcast_mut::<StartDrag>() {
+                self.drag = Some(Drag::new(source, start, position));
+                self.ctx.pointer_capture = None;
+                break;
+            }
+        }
+    }
+
+    fn drag_input(&mut self, input: &Input, events: &mut Events) -> bool {
+        let len = events.len();
+        let consumed = match input {
+            Input::CursorMoved(m) => {
+                self.drag.as_mut().unwrap().position = m.mouse_state.position;
+                self.update_drag_target(events);
+                true
+            }
+            Input::MouseInput(m) if m.button_state == ButtonState::Released => {
+                self.drag.as_mut().unwrap().position = m.mouse_state.position;
+                self.update_drag_target(events);
+                self.end_drag(true, events);
+                true
+            }
+            Input::MouseInput(_) | Input::MouseWheel(_) => true,
+            Input::KeyInput(k) if k.vkey == VirtualKey::Esc => {
+                if k.key_state == KeyState::Pressed {
+                    self.end_drag(false, events);
+                }
+                true
+            }
+            Input::CursorLeft(_) | Input::FocusLost => {
+                self.end_drag(false, events);
+                false
+            }
+            _ => false,
+        };
+        for event in events.iter().skip(len) {
+            self.ctx.layout_cache.invalidate(event.handle().id());
+        }
+        consumed
+    }
+
+    fn deliver_drag(&mut self, target: AnyHandle, kind: DragKind, events: &mut Events) -> bool {
+        let Some(drag) = self.drag.as_mut() else {
+            return false;
+        };
+        let event = drag.event(kind);
+        let mut funcs = ApplyFuncs::dispatch(
+            &self.ctx,
+            Delivery::Drag(event),
+            std::mem::take(events),
+            vec![target.id()],
+            false,
+        );
+        self.root.apply(&mut funcs);
+        let dispatch = funcs.dispatch.unwrap();
+        *events = dispatch.events;
+        let Delivery::Drag(event) = dispatch.delivery else {
+            unreachable!()
+        };
+        self.drag.as_mut().unwrap().restore(event)
+    }
+
+    fn update_drag_target(&mut self, events: &mut Events) {
+        let Some(drag) = self.drag.as_ref() else {
+            return;
+        };
+        let current = drag.target;
+        let mut path = vec![];
+        if let Some(hit) = self.hit(drag.position) {
+            path.push(hit);
+            if let Some(ancestors) = self.ctx.layout.ancestors_of(hit.id()) {
+                path.extend(ancestors.iter().rev());
+            }
+        }
+        let mut target = None;
+        for candidate in path {
+            let kind = if Some(candidate) == current {
+                DragKind::Over
+            } else {
+                DragKind::Enter
+            };
+            if self.deliver_drag(candidate, kind, events) {
+                target = Some(candidate);
+                break;
+            }
+        }
+        if let Some(current) = current
+            && Some(current) != target
+        {
+            self.deliver_drag(current, DragKind::Leave, events);
+        }
+        self.drag.as_mut().unwrap().target = target;
+    }
+
+    fn end_drag(&mut self, drop: bool, events: &mut Events) {
+        let Some(drag) = self.drag.as_ref() else {
+            return;
+        };
+        let source = drag.source;
+        let dropped = match drag.target {
+            Some(target) if drop => {
+                self.deliver_drag(target, DragKind::Drop, events);
+                true
+            }
+            Some(target) => {
+                self.deliver_drag(target, DragKind::Leave, events);
+                false
+            }
+            None => false,
+        };
+        self.deliver_drag(source, DragKind::End { dropped }, events);
+        self.drag = None;
+        self.ctx.layout_cache.invalidate_all();
+    }
+
+    #[inline]
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    fn input_target(&self, input: &Input) -> Option<AnyHandle> {
+        match input.position() {
+            Some(position) => self.hit(position),
+            None => match input {
+                Input::KeyInput(_)
+                | Input::CharInput(_)
+                | Input::ImeBeginComposition
+                | Input::ImeUpdateComposition(_)
+                | Input::ImeEndComposition(_) => self.ctx.focus,
+                _ => None,
+            },
+        }
+    }
+
+    fn route(&mut self, input: &Input, target: AnyHandle, events: &mut Events) -> bool {
+        let mut ids = self
+            .ctx
+            .layout
+            .ancestors_of(target.id())
+            .map(|ancestors| ancestors.iter().map(|a| a.id()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        ids.push(target.id());
+        let mut routed = RoutedInput::new(input, target);
+        let passes = std::iter::once((ids.clone(), false))
+            .chain(ids.iter().rev().skip(1).map(|id| (vec![*id], true)));
+        for (ids, bubble) in passes {
+            let mut funcs = ApplyFuncs::dispatch(
+                &self.ctx,
+                Delivery::Routed(routed),
+                std::mem::take(events),
+                ids,
+                bubble,
+            );
+            self.root.apply(&mut funcs);
+            let dispatch = funcs.dispatch.unwrap();
+            *events = dispatch.events;
+            let Delivery::Routed(input) = dispatch.delivery else {
+                unreachable!()
+            };
+            routed = input;
+            if routed.is_propagation_stopped() {
+                break;
+            }
+        }
+        routed.is_propagation_stopped()
+    }
+
+    fn change_focus(&mut self, focus: Option<AnyHandle>) {
+        if focus == self.ctx.focus {
+            return;
+        }
+        for handle in self.ctx.focus.iter().chain(focus.iter()) {
+            self.ctx.layout_cache.invalidate(handle.id());
+        }
+        self.ctx.focus = focus;
+    }
+
+    fn move_focus(&mut self, forward: bool) {
+        let order = self.ctx.layout.focus_order();
+        if order.is_empty() {
+            return;
+        }
+        let current = self
+            .ctx
+            .focus
+            .and_then(|focus| order.iter().position(|h| *h == focus));
+        let index = match (current, forward) {
+            (Some(i), true) => (i + 1) % order.len(),
+            (Some(i), false) => (i + order.len() - 1) % order.len(),
+            (None, true) => 0,
+            (None, false) => order.len() - 1,
+        };
+        self.change_focus(Some(order[index]));
+    }
+
+    #[inline]
+    pub fn focus_next(&mut self) {
+        self.move_focus(true);
+    }
+
+    #[inline]
+    pub fn focus_prev(&mut self) {
+        self.move_focus(false);
+    }
+
+    #[inline]
+    pub fn set_focus<T>(&mut self, handle: &Handle<T>)
+    where
+        T: Widget,
+    {
+        self.change_focus(Some(handle.clone().into()));
+    }
+
+    #[inline]
+    pub fn clear_focus(&mut self) {
+        self.change_focus(None);
+    }
+
+    #[inline]
+    pub fn set_enabled<T>(&mut self, handle: &Handle<T>, enabled: bool)
+    where
+        T: Widget,
+    {
+        let changed = if enabled {
+            self.ctx.disabled.remove(&handle.id())
+        } else {
+            self.ctx.disabled.insert(handle.id())
+        };
+        if changed {
+            self.ctx.layout_cache.invalidate(handle.id());
+        }
+    }
+
+    #[inline]
+    pub fn is_enabled<T>(&self, handle: &Handle<T>) -> bool
+    where
+        T: Widget,
+    {
+        self.ctx.is_enabled(handle)
     }
 
     #[inline]
@@ -138,17 +858,150 @@ impl Scene {
         self.apply_funcs.push(handle, f);
     }
 
+    #[inline]
     pub fn layout(&mut self) -> Arc<Layout> {
-        if !self.apply_funcs.0.is_empty() {
-            self.root.apply(&mut self.apply_funcs);
-            self.apply_funcs.0.clear();
+        self.layout_at(Instant::now())
+    }
+
+    pub fn layout_at(&mut self, now: Instant) -> Arc<Layout> {
+        for id in self.ctx.animations.running() {
+            self.ctx.layout_cache.invalidate(id);
         }
+        self.ctx.update_time(now);
+        self.flush_apply_funcs();
+        self.ctx.styles.get_mut().clear();
+        self.ctx.layout_cache.begin(&self.ctx.layout);
+        self.ctx.animations.begin_frame();
         let mut layout = LayoutConstructor::new();
-        self.root.layout(LayoutContext::new(&self.ctx), &mut layout);
+        layout.layout_child(self.root.as_ref(), LayoutContext::new(&self.ctx));
+        self.tooltips
+            .layout(LayoutContext::new(&self.ctx), &mut layout);
+        if let Some(drag) = self.drag.as_ref() {
+            self.drag_previews
+                .layout(drag, LayoutContext::new(&self.ctx), &mut layout);
+        }
+        self.ctx.animations.keep(|id| layout.reused.contains(&id));
+        self.ctx.animations.end_frame();
+        self.ctx.layout_cache.end();
         self.ctx.layout = Arc::new(Layout::new(&self.ctx, layout));
+        if let Some(focus) = self.ctx.focus
+            && self.ctx.is_disabled(&focus)
+        {
+            self.change_focus(None);
+        }
         self.ctx.layout.clone()
     }
 
+    #[inline]
+    pub fn invalidate<T>(&mut self, handle: &Handle<T>)
+    where
+        T: Widget,
+    {
+        self.ctx.layout_cache.invalidate(handle.id());
+    }
+
+    #[inline]
+    pub fn theme(&self) -> &Theme {
+        &self.ctx.theme
+    }
+
+    #[inline]
+    pub fn theme_mut(&mut self) -> &mut Theme {
+        self.ctx.layout_cache.invalidate_all();
+        self.ctx.styles.get_mut().clear();
+        &mut self.ctx.theme
+    }
+
+    #[inline]
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.ctx.layout_cache.invalidate_all();
+        self.ctx.styles.get_mut().clear();
+        self.ctx.theme = theme;
+    }
+
+    #[inline]
+    pub fn set_style<T>(&mut self, handle: &Handle<T>, style: WidgetStyle)
+    where
+        T: Widget,
+    {
+        self.ctx.layout_cache.invalidate(handle.id());
+        self.ctx.styles.get_mut().clear();
+        self.ctx.theme.set_widget(handle, style);
+    }
+
+    #[inline]
+    pub fn remove_style<T>(&mut self, handle: &Handle<T>)
+    where
+        T: Widget,
+    {
+        self.ctx.layout_cache.invalidate(handle.id());
+        self.ctx.styles.get_mut().clear();
+        self.ctx.theme.remove_widget(handle);
+    }
+
+    #[inline]
+    pub fn set_tooltip<T>(&mut self, handle: &Handle<T>, text: impl Into<String>)
+    where
+        T: Widget,
+    {
+        self.tooltips.insert(handle.id(), text.into());
+    }
+
+    #[inline]
+    pub fn remove_tooltip<T>(&mut self, handle: &Handle<T>)
+    where
+        T: Widget,
+    {
+        self.tooltips.remove(handle.id());
+    }
+
+    #[inline]
+    pub fn set_tooltip_delay(&mut self, delay: Duration) {
+        self.tooltips.delay = delay;
+    }
+
+    #[inline]
+    pub fn set_double_click_interval(&mut self, interval: Duration) {
+        self.clicks.interval = interval;
+    }
+
+    #[inline]
+    pub fn set_double_click_distance(&mut self, distance: f32) {
+        self.clicks.distance = distance;
+    }
+
+    #[inline]
+    pub fn set_pan_slop(&mut self, slop: f32) {
+        self.touches.pan_slop = slop;
+    }
+
+    #[inline]
+    pub fn set_long_press_delay(&mut self, delay: Duration) {
+        self.clicks.long_press_delay = delay;
+    }
+
+    #[inline]
+    pub fn is_animating(&self) -> bool {
+        self.ctx.animations.is_animating()
+    }
+
+    #[inline]
+    pub fn next_deadline(&self) -> Option<Instant> {
+        let deadline = self
+            .ctx
+            .deadlines
+            .borrow()
+            .iter()
+            .map(|(_, deadline)| *deadline)
+            .min();
+        [self.tooltips.deadline(&self.ctx), self.clicks.deadline()]
+            .into_iter()
+            .fold(deadline, |a, b| match (a, b) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            })
+    }
+
     #[inline]
     pub fn push_child<T, U>(&mut self, parent: impl Into<Handle<T>>, child: U) -> Handle<U>
     where
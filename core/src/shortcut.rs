@@ -0,0 +1,268 @@
+use super::*;
+
+const DIGITS: [VirtualKey; 10] = [
+    VirtualKey::Key0,
+    VirtualKey::Key1,
+    VirtualKey::Key2,
+    VirtualKey::Key3,
+    VirtualKey::Key4,
+    VirtualKey::Key5,
+    VirtualKey::Key6,
+    VirtualKey::Key7,
+    VirtualKey::Key8,
+    VirtualKey::Key9,
+];
+
+const LETTERS: [VirtualKey; 26] = [
+    VirtualKey::A,
+    VirtualKey::B,
+    VirtualKey::C,
+    VirtualKey::D,
+    VirtualKey::E,
+    VirtualKey::F,
+    VirtualKey::G,
+    VirtualKey::H,
+    VirtualKey::I,
+    VirtualKey::J,
+    VirtualKey::K,
+    VirtualKey::L,
+    VirtualKey::M,
+    VirtualKey::N,
+    VirtualKey::O,
+    VirtualKey::P,
+    VirtualKey::Q,
+    VirtualKey::R,
+    VirtualKey::S,
+    VirtualKey::T,
+    VirtualKey::U,
+    VirtualKey::V,
+    VirtualKey::W,
+    VirtualKey::X,
+    VirtualKey::Y,
+    VirtualKey::Z,
+];
+
+const FUNCTIONS: [VirtualKey; 24] = [
+    VirtualKey::F1,
+    VirtualKey::F2,
+    VirtualKey::F3,
+    VirtualKey::F4,
+    VirtualKey::F5,
+    VirtualKey::F6,
+    VirtualKey::F7,
+    VirtualKey::F8,
+    VirtualKey::F9,
+    VirtualKey::F10,
+    VirtualKey::F11,
+    VirtualKey::F12,
+    VirtualKey::F13,
+    VirtualKey::F14,
+    VirtualKey::F15,
+    VirtualKey::F16,
+    VirtualKey::F17,
+    VirtualKey::F18,
+    VirtualKey::F19,
+    VirtualKey::F20,
+    VirtualKey::F21,
+    VirtualKey::F22,
+    VirtualKey::F23,
+    VirtualKey::F24,
+];
+
+const NAMED: [(VirtualKey, &str); 16] = [
+    (VirtualKey::Esc, "Esc"),
+    (VirtualKey::Tab, "Tab"),
+    (VirtualKey::BackSpace, "Backspace"),
+    (VirtualKey::Enter, "Enter"),
+    (VirtualKey::Space, "Space"),
+    (VirtualKey::Insert, "Insert"),
+    (VirtualKey::Delete, "Delete"),
+    (VirtualKey::Home, "Home"),
+    (VirtualKey::End, "End"),
+    (VirtualKey::PageUp, "PageUp"),
+    (VirtualKey::PageDown, "PageDown"),
+    (VirtualKey::Up, "Up"),
+    (VirtualKey::Down, "Down"),
+    (VirtualKey::Left, "Left"),
+    (VirtualKey::Right, "Right"),
+    (VirtualKey::Pause, "Pause"),
+];
+
+fn parse_key(s: &str) -> Option<VirtualKey> {
+    let mut chars = s.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        let c = c.to_ascii_uppercase();
+        return match c {
+            '0'..='9' => Some(DIGITS[c as usize - '0' as usize]),
+            'A'..='Z' => Some(LETTERS[c as usize - 'A' as usize]),
+            _ => None,
+        };
+    }
+    if let Some(n) = s
+        .strip_prefix(['F', 'f'])
+        .and_then(|n| n.parse::<usize>().ok())
+    {
+        return FUNCTIONS.get(n.checked_sub(1)?).copied();
+    }
+    NAMED
+        .iter()
+        .find(|(_, name)| name.eq_ignore_ascii_case(s))
+        .map(|(vkey, _)| *vkey)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Shortcut {
+    pub modifiers: Modifiers,
+    pub vkey: VirtualKey,
+}
+
+impl Shortcut {
+    #[inline]
+    pub fn new(modifiers: Modifiers, vkey: VirtualKey) -> Self {
+        Self { modifiers, vkey }
+    }
+
+    #[inline]
+    pub fn matches(&self, input: &Input) -> bool {
+        match input {
+            Input::KeyInput(k) => {
+                k.key_state == KeyState::Pressed
+                    && !k.repeat
+                    && k.vkey == self.vkey
+                    && k.modifiers == self.modifiers
+            }
+            _ => false,
+        }
+    }
+}
+
+impl std::str::FromStr for Shortcut {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('+').map(str::trim).collect::<Vec<_>>();
+        let key = parts.pop().ok_or(())?;
+        let mut modifiers = Modifiers::NONE;
+        for part in parts {
+            modifiers |= match part.to_ascii_lowercase().as_str() {
+                "shift" => Modifiers::SHIFT,
+                "ctrl" | "control" => Modifiers::CTRL,
+                "alt" => Modifiers::ALT,
+                "meta" | "win" | "cmd" => Modifiers::META,
+                _ => return Err(()),
+            };
+        }
+        Ok(Self::new(modifiers, parse_key(key).ok_or(())?))
+    }
+}
+
+impl std::fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (on, name) in [
+            (self.modifiers.ctrl, "Ctrl"),
+            (self.modifiers.alt, "Alt"),
+            (self.modifiers.shift, "Shift"),
+            (self.modifiers.meta, "Meta"),
+        ] {
+            if on {
+                write!(f, "{name}+")?;
+            }
+        }
+        if let Some(i) = DIGITS.iter().position(|k| *k == self.vkey) {
+            return write!(f, "{i}");
+        }
+        match NAMED.iter().find(|(vkey, _)| *vkey == self.vkey) {
+            Some((_, name)) => write!(f, "{name}"),
+            None => write!(f, "{}", self.vkey),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(vkey: VirtualKey, modifiers: Modifiers) -> KeyInput {
+        KeyInput {
+            vkey,
+            scan_code: ScanCode(0),
+            key_state: KeyState::Pressed,
+            modifiers,
+            repeat: false,
+        }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "Ctrl+S".parse(),
+            Ok(Shortcut::new(Modifiers::CTRL, VirtualKey::S))
+        );
+        assert_eq!(
+            "control + shift + f5".parse(),
+            Ok(Shortcut::new(
+                Modifiers::CTRL | Modifiers::SHIFT,
+                VirtualKey::F5
+            ))
+        );
+        assert_eq!(
+            "Win+PageDown".parse(),
+            Ok(Shortcut::new(Modifiers::META, VirtualKey::PageDown))
+        );
+        assert_eq!(
+            "7".parse(),
+            Ok(Shortcut::new(Modifiers::NONE, VirtualKey::Key7))
+        );
+        assert_eq!("F0".parse::<Shortcut>(), Err(()));
+        assert_eq!("F25".parse::<Shortcut>(), Err(()));
+        assert_eq!("Hyper+A".parse::<Shortcut>(), Err(()));
+    }
+
+    #[test]
+    fn parse_modifier_only() {
+        assert_eq!("Ctrl".parse::<Shortcut>(), Err(()));
+        assert_eq!("Ctrl+".parse::<Shortcut>(), Err(()));
+        assert_eq!("Ctrl+Shift".parse::<Shortcut>(), Err(()));
+        assert_eq!("".parse::<Shortcut>(), Err(()));
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for s in ["Ctrl+Shift+Z", "Alt+F4", "Meta+Space", "Ctrl+0", "Delete"] {
+            let shortcut = s.parse::<Shortcut>().unwrap();
+            assert_eq!(shortcut.to_string(), s);
+        }
+    }
+
+    #[test]
+    fn matches() {
+        let shortcut = Shortcut::new(Modifiers::CTRL, VirtualKey::S);
+        let input = key(VirtualKey::S, Modifiers::CTRL);
+        assert!(shortcut.matches(&Input::KeyInput(input.clone())));
+        let extra = key(VirtualKey::S, Modifiers::CTRL | Modifiers::SHIFT);
+        assert!(!shortcut.matches(&Input::KeyInput(extra)));
+        let none = key(VirtualKey::S, Modifiers::NONE);
+        assert!(!shortcut.matches(&Input::KeyInput(none)));
+        let repeat = KeyInput {
+            repeat: true,
+            ..input.clone()
+        };
+        assert!(!shortcut.matches(&Input::KeyInput(repeat)));
+        let released = KeyInput {
+            key_state: KeyState::Released,
+            ..input
+        };
+        assert!(!shortcut.matches(&Input::KeyInput(released)));
+        assert!(!shortcut.matches(&Input::CharInput('s')));
+    }
+
+    #[test]
+    fn modifier_press_does_not_match() {
+        let shortcut = Shortcut::new(Modifiers::CTRL, VirtualKey::S);
+        let ctrl = key(VirtualKey::Ctrl, Modifiers::CTRL);
+        assert!(!shortcut.matches(&Input::KeyInput(ctrl)));
+        let meta = Shortcut::new(Modifiers::META, VirtualKey::E);
+        assert!(!meta.matches(&Input::KeyInput(key(LEFT_META, Modifiers::META))));
+        assert!(meta.matches(&Input::KeyInput(key(VirtualKey::E, Modifiers::META))));
+    }
+}
@@ -0,0 +1,183 @@
+use super::*;
+use std::collections::HashMap;
+
+const CELL_SIZE: f32 = 64.0;
+
+fn intersect(a: &LogicalRect<f32>, b: &LogicalRect<f32>) -> Option<LogicalRect<f32>> {
+    let rect = LogicalRect::new(
+        a.left.max(b.left),
+        a.top.max(b.top),
+        a.right.min(b.right),
+        a.bottom.min(b.bottom),
+    );
+    (rect.left < rect.right && rect.top < rect.bottom).then_some(rect)
+}
+
+fn cell(v: f32) -> i32 {
+    (v / CELL_SIZE).floor() as i32
+}
+
+pub(crate) fn clip_rects(v: &[LayoutElement]) -> Vec<Option<LogicalRect<f32>>> {
+    let mut stack: Vec<Option<LogicalRect<f32>>> = vec![];
+    v.iter()
+        .map(|e| match e {
+            LayoutElement::StartClipping(c) => {
+                let clip = match stack.last() {
+                    Some(Some(top)) => intersect(top, &c.rect),
+                    Some(None) => None,
+                    None => Some(c.rect),
+                };
+                stack.push(clip);
+                None
+            }
+            LayoutElement::EndClipping(_) => {
+                stack.pop();
+                None
+            }
+            _ => match stack.last() {
+                Some(Some(clip)) => intersect(clip, e.rect()),
+                Some(None) => None,
+                None => Some(*e.rect()),
+            },
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SpatialIndex {
+    rects: Vec<Option<LogicalRect<f32>>>,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl SpatialIndex {
+    pub fn new(rects: Vec<Option<LogicalRect<f32>>>) -> Self {
+        let mut cells: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+        for (i, rect) in rects.iter().enumerate() {
+            let Some(rect) = rect else {
+                continue;
+            };
+            for y in cell(rect.top)..=cell(rect.bottom) {
+                for x in cell(rect.left)..=cell(rect.right) {
+                    cells.entry((x, y)).or_default().push(i);
+                }
+            }
+        }
+        Self { rects, cells }
+    }
+
+    pub fn query(&self, position: LogicalPosition<f32>) -> impl Iterator<Item = usize> + '_ {
+        self.cells
+            .get(&(cell(position.x), cell(position.y)))
+            .into_iter()
+            .flat_map(|indices| indices.iter().rev().copied())
+            .filter(move |i| {
+                self.rects[*i].is_some_and(|r| {
+                    r.left <= position.x
+                        && position.x < r.right
+                        && r.top <= position.y
+                        && position.y < r.bottom
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Dummy(Id);
+
+    impl HasId for Dummy {
+        fn id(&self) -> Id {
+            self.0
+        }
+    }
+
+    impl Widget for Dummy {
+        fn input(&mut self, _ctx: &Context, _input: &Input, _events: &mut Events) -> ControlFlow {
+            ControlFlow::Continue
+        }
+
+        fn apply(&mut self, _funcs: &mut ApplyFuncs) {}
+
+        fn size(&self, _ctx: &LayoutContext) -> LogicalSize<f32> {
+            LogicalSize::new(0.0, 0.0)
+        }
+
+        fn size_types(&self) -> SizeTypes {
+            SizeTypes::fix()
+        }
+
+        fn layout(&self, _lc: LayoutContext, _result: &mut LayoutConstructor) {}
+    }
+
+    fn rect(left: f32, top: f32, right: f32, bottom: f32) -> LogicalRect<f32> {
+        LogicalRect::new(left, top, right, bottom)
+    }
+
+    fn area(widget: &Dummy, rect: LogicalRect<f32>) -> LayoutElement {
+        LayoutElement::area(widget, WidgetState::None, rect, &[], 0, false)
+    }
+
+    fn query(index: &SpatialIndex, x: f32, y: f32) -> Vec<usize> {
+        index.query(LogicalPosition::new(x, y)).collect()
+    }
+
+    #[test]
+    fn query_across_cells() {
+        let index = SpatialIndex::new(vec![
+            Some(rect(0.0, 0.0, 200.0, 200.0)),
+            Some(rect(100.0, 100.0, 150.0, 150.0)),
+            None,
+            Some(rect(-100.0, -100.0, -10.0, -10.0)),
+        ]);
+        assert_eq!(query(&index, 10.0, 10.0), vec![0]);
+        assert_eq!(query(&index, 120.0, 120.0), vec![1, 0]);
+        assert_eq!(query(&index, 199.0, 130.0), vec![0]);
+        assert_eq!(query(&index, 200.0, 130.0), Vec::<usize>::new());
+        assert_eq!(query(&index, 150.0, 150.0), vec![0]);
+        assert_eq!(query(&index, -50.0, -50.0), vec![3]);
+        assert_eq!(query(&index, -5.0, -5.0), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn clip_rects_intersect_nested_clips() {
+        let widget = Dummy(Id::new());
+        let v = vec![
+            LayoutElement::start_clipping(&widget, rect(0.0, 0.0, 100.0, 100.0), &[], 0),
+            area(&widget, rect(50.0, 50.0, 150.0, 150.0)),
+            LayoutElement::start_clipping(&widget, rect(200.0, 0.0, 300.0, 100.0), &[], 0),
+            area(&widget, rect(0.0, 0.0, 300.0, 100.0)),
+            LayoutElement::end_clipping(&widget, rect(200.0, 0.0, 300.0, 100.0), &[], 0),
+            LayoutElement::end_clipping(&widget, rect(0.0, 0.0, 100.0, 100.0), &[], 0),
+            area(&widget, rect(50.0, 50.0, 150.0, 150.0)),
+        ];
+        assert_eq!(
+            clip_rects(&v),
+            vec![
+                None,
+                Some(rect(50.0, 50.0, 100.0, 100.0)),
+                None,
+                None,
+                None,
+                None,
+                Some(rect(50.0, 50.0, 150.0, 150.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn query_respects_clips() {
+        let widget = Dummy(Id::new());
+        let v = vec![
+            LayoutElement::start_clipping(&widget, rect(0.0, 0.0, 100.0, 100.0), &[], 0),
+            area(&widget, rect(50.0, 50.0, 150.0, 150.0)),
+            LayoutElement::end_clipping(&widget, rect(0.0, 0.0, 100.0, 100.0), &[], 0),
+        ];
+        let index = SpatialIndex::new(clip_rects(&v));
+        assert_eq!(query(&index, 75.0, 75.0), vec![1]);
+        assert_eq!(query(&index, 120.0, 120.0), Vec::<usize>::new());
+        assert_eq!(query(&index, 10.0, 10.0), Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,418 @@
+use super::*;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+    pub const BLACK: Self = Self::rgb(0.0, 0.0, 0.0);
+    pub const WHITE: Self = Self::rgb(1.0, 1.0, 1.0);
+
+    #[inline]
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    #[inline]
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::new(r, g, b, 1.0)
+    }
+
+    #[inline]
+    pub const fn gray(v: f32) -> Self {
+        Self::rgb(v, v, v)
+    }
+
+    #[inline]
+    pub fn with_alpha(self, a: f32) -> Self {
+        Self { a, ..self }
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Color {
+    #[inline]
+    fn from(value: (f32, f32, f32, f32)) -> Self {
+        Self::new(value.0, value.1, value.2, value.3)
+    }
+}
+
+impl From<(f32, f32, f32)> for Color {
+    #[inline]
+    fn from(value: (f32, f32, f32)) -> Self {
+        Self::rgb(value.0, value.1, value.2)
+    }
+}
+
+impl From<Color> for (f32, f32, f32, f32) {
+    #[inline]
+    fn from(value: Color) -> Self {
+        (value.r, value.g, value.b, value.a)
+    }
+}
+
+impl Interpolate for Color {
+    #[inline]
+    fn interpolate(&self, to: &Self, t: f32) -> Self {
+        Self::new(
+            self.r.interpolate(&to.r, t),
+            self.g.interpolate(&to.g, t),
+            self.b.interpolate(&to.b, t),
+            self.a.interpolate(&to.a, t),
+        )
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct StateColors {
+    pub normal: Color,
+    pub hover: Option<Color>,
+    pub pressed: Option<Color>,
+    pub disabled: Option<Color>,
+    pub focused: Option<Color>,
+}
+
+impl StateColors {
+    #[inline]
+    pub fn new(normal: impl Into<Color>) -> Self {
+        Self {
+            normal: normal.into(),
+            hover: None,
+            pressed: None,
+            disabled: None,
+            focused: None,
+        }
+    }
+
+    #[inline]
+    pub fn hover(mut self, color: impl Into<Color>) -> Self {
+        self.hover = Some(color.into());
+        self
+    }
+
+    #[inline]
+    pub fn pressed(mut self, color: impl Into<Color>) -> Self {
+        self.pressed = Some(color.into());
+        self
+    }
+
+    #[inline]
+    pub fn disabled(mut self, color: impl Into<Color>) -> Self {
+        self.disabled = Some(color.into());
+        self
+    }
+
+    #[inline]
+    pub fn focused(mut self, color: impl Into<Color>) -> Self {
+        self.focused = Some(color.into());
+        self
+    }
+
+    pub fn get(&self, state: WidgetState, focused: bool) -> Color {
+        let normal = if focused {
+            self.focused.unwrap_or(self.normal)
+        } else {
+            self.normal
+        };
+        match state {
+            WidgetState::None => normal,
+            WidgetState::Hover => self.hover.unwrap_or(normal),
+            WidgetState::Pressed => self.pressed.or(self.hover).unwrap_or(normal),
+            WidgetState::Disabled => self.disabled.unwrap_or(self.normal),
+        }
+    }
+}
+
+impl From<Color> for StateColors {
+    #[inline]
+    fn from(value: Color) -> Self {
+        Self::new(value)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[non_exhaustive]
+pub struct BoxShadow {
+    pub color: Color,
+    pub offset: LogicalPosition<f32>,
+    pub blur: f32,
+    pub spread: f32,
+}
+
+impl BoxShadow {
+    #[inline]
+    pub fn new(
+        color: impl Into<Color>,
+        offset: impl Into<LogicalPosition<f32>>,
+        blur: f32,
+    ) -> Self {
+        Self {
+            color: color.into(),
+            offset: offset.into(),
+            blur,
+            spread: 0.0,
+        }
+    }
+
+    #[inline]
+    pub fn spread(mut self, spread: f32) -> Self {
+        self.spread = spread;
+        self
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct WidgetStyle {
+    pub background: Option<StateColors>,
+    pub foreground: Option<StateColors>,
+    pub border: Option<StateColors>,
+    pub border_width: Option<f32>,
+    pub font: Option<Font>,
+    pub padding: Option<LogicalRect<f32>>,
+    pub corner_radius: Option<f32>,
+    pub shadow: Option<BoxShadow>,
+    pub opacity: Option<f32>,
+}
+
+impl WidgetStyle {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn background(&self, state: WidgetState, focused: bool) -> Option<Color> {
+        self.background.map(|c| c.get(state, focused))
+    }
+
+    #[inline]
+    pub fn foreground(&self, state: WidgetState, focused: bool) -> Option<Color> {
+        self.foreground.map(|c| c.get(state, focused))
+    }
+
+    #[inline]
+    pub fn border(&self, state: WidgetState, focused: bool) -> Option<Color> {
+        self.border.map(|c| c.get(state, focused))
+    }
+
+    fn or(mut self, fallback: &Self) -> Self {
+        self.background = self.background.or(fallback.background);
+        self.foreground = self.foreground.or(fallback.foreground);
+        self.border = self.border.or(fallback.border);
+        self.border_width = self.border_width.or(fallback.border_width);
+        if self.font.is_none() {
+            self.font.clone_from(&fallback.font);
+        }
+        self.padding = self.padding.or(fallback.padding);
+        self.corner_radius = self.corner_radius.or(fallback.corner_radius);
+        self.shadow = self.shadow.or(fallback.shadow);
+        self.opacity = self.opacity.or(fallback.opacity);
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Theme {
+    colors: HashMap<String, Color>,
+    fonts: HashMap<String, Font>,
+    base: WidgetStyle,
+    classes: HashMap<String, WidgetStyle>,
+    overrides: HashMap<Id, WidgetStyle>,
+}
+
+impl Theme {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            colors: HashMap::new(),
+            fonts: HashMap::new(),
+            base: WidgetStyle::new(),
+            classes: HashMap::new(),
+            overrides: HashMap::new(),
+        }
+    }
+
+    pub fn dark() -> Self {
+        let mut theme = Self::new();
+        theme.set_color("background", Color::new(0.0, 0.0, 0.3, 0.0));
+        theme.set_color("foreground", Color::WHITE);
+        theme.set_color("control", Color::gray(0.3));
+        theme.set_color("control.hover", Color::gray(0.4));
+        theme.set_color("control.pressed", Color::gray(0.6));
+        theme.set_color("control.disabled", Color::gray(0.2));
+        theme.set_color("accent", Color::gray(0.8));
+        theme.set_color("border", Color::gray(0.3));
+        theme.set_color("popup", Color::new(0.1, 0.1, 0.1, 0.9));
+        theme.set_color("selection", Color::rgb(0.0, 0.3, 0.0));
+        theme.set_color("text.disabled", Color::gray(0.5));
+        theme.apply_palette();
+        theme
+    }
+
+    pub fn light() -> Self {
+        let mut theme = Self::new();
+        theme.set_color("background", Color::gray(0.95));
+        theme.set_color("foreground", Color::BLACK);
+        theme.set_color("control", Color::gray(0.85));
+        theme.set_color("control.hover", Color::gray(0.78));
+        theme.set_color("control.pressed", Color::gray(0.65));
+        theme.set_color("control.disabled", Color::gray(0.9));
+        theme.set_color("accent", Color::rgb(0.1, 0.4, 0.8));
+        theme.set_color("border", Color::gray(0.6));
+        theme.set_color("popup", Color::gray(1.0));
+        theme.set_color("selection", Color::rgb(0.7, 0.85, 1.0));
+        theme.set_color("text.disabled", Color::gray(0.55));
+        theme.apply_palette();
+        theme
+    }
+
+    fn apply_palette(&mut self) {
+        let color = |name: &str| self.colors[name];
+        let foreground = StateColors::new(color("foreground")).disabled(color("text.disabled"));
+        let control = StateColors::new(color("control"))
+            .hover(color("control.hover"))
+            .pressed(color("control.pressed"))
+            .disabled(color("control.disabled"));
+        let accent = StateColors::new(color("accent"));
+        let border = StateColors::new(color("border")).focused(color("accent"));
+        let popup = StateColors::new(color("popup"));
+        let check = StateColors::new(color("accent")).disabled(color("text.disabled"));
+        let item = StateColors::new(Color::TRANSPARENT)
+            .hover(color("control.hover"))
+            .pressed(color("control.hover"));
+        let classes = [
+            ("button", Some(control), None),
+            ("check_box", Some(control), None),
+            ("check_box.check", None, Some(check)),
+            ("dropdown_box", Some(control), Some(border)),
+            ("list_box", Some(popup), Some(border)),
+            ("menu", Some(popup), Some(border)),
+            ("menu.item", Some(item), None),
+            (
+                "menu.separator",
+                Some(StateColors::new(color("border"))),
+                None,
+            ),
+            (
+                "menu.underline",
+                Some(StateColors::new(color("foreground"))),
+                None,
+            ),
+            ("menu_bar.title", Some(item), None),
+            ("scroll_bar", Some(StateColors::new(color("control"))), None),
+            ("scroll_bar.thumb", Some(accent), None),
+            ("slider", Some(StateColors::new(color("control"))), None),
+            ("slider.knob", Some(accent), None),
+            ("text_box", None, Some(border)),
+            ("tooltip", Some(popup), Some(border)),
+            ("drag_preview", Some(popup), Some(border)),
+        ];
+        let shadow = BoxShadow::new(Color::BLACK.with_alpha(0.4), (2.0, 2.0), 4.0);
+        self.base.foreground = Some(foreground);
+        self.base.border_width = Some(1.0);
+        self.base.corner_radius = Some(0.0);
+        for (name, background, border) in classes {
+            let style = self.class_mut(name);
+            style.background = background;
+            style.border = border;
+        }
+        self.class_mut("list_box").border_width = Some(2.0);
+        self.class_mut("text_box").border_width = Some(2.0);
+        self.class_mut("check_box.check").border_width = Some(2.0);
+        self.class_mut("button").corner_radius = Some(3.0);
+        self.class_mut("menu").shadow = Some(shadow);
+        self.class_mut("tooltip").shadow = Some(shadow);
+    }
+
+    #[inline]
+    pub fn color(&self, name: &str) -> Option<Color> {
+        self.colors.get(name).copied()
+    }
+
+    #[inline]
+    pub fn set_color(&mut self, name: impl Into<String>, color: impl Into<Color>) {
+        self.colors.insert(name.into(), color.into());
+    }
+
+    #[inline]
+    pub fn font(&self, name: &str) -> Option<&Font> {
+        self.fonts.get(name)
+    }
+
+    /// A font named after a class is used by that class, and `"default"` by every widget,
+    /// unless a style sets its own font.
+    #[inline]
+    pub fn set_font(&mut self, name: impl Into<String>, font: Font) {
+        self.fonts.insert(name.into(), font);
+    }
+
+    #[inline]
+    pub fn base(&self) -> &WidgetStyle {
+        &self.base
+    }
+
+    #[inline]
+    pub fn base_mut(&mut self) -> &mut WidgetStyle {
+        &mut self.base
+    }
+
+    #[inline]
+    pub fn class(&self, name: &str) -> Option<&WidgetStyle> {
+        self.classes.get(name)
+    }
+
+    #[inline]
+    pub fn class_mut(&mut self, name: impl Into<String>) -> &mut WidgetStyle {
+        self.classes.entry(name.into()).or_default()
+    }
+
+    #[inline]
+    pub fn set_class(&mut self, name: impl Into<String>, style: WidgetStyle) {
+        self.classes.insert(name.into(), style);
+    }
+
+    #[inline]
+    pub fn widget(&self, widget: &impl HasId) -> Option<&WidgetStyle> {
+        self.overrides.get(&widget.id())
+    }
+
+    #[inline]
+    pub fn set_widget(&mut self, widget: &impl HasId, style: WidgetStyle) {
+        self.overrides.insert(widget.id(), style);
+    }
+
+    #[inline]
+    pub fn remove_widget(&mut self, widget: &impl HasId) {
+        self.overrides.remove(&widget.id());
+    }
+
+    pub fn resolve(&self, widget: &impl HasId, class: &str) -> WidgetStyle {
+        let mut style = self
+            .overrides
+            .get(&widget.id())
+            .cloned()
+            .unwrap_or_default();
+        if let Some(class) = self.classes.get(class) {
+            style = style.or(class);
+        }
+        if style.font.is_none() {
+            style.font = self.fonts.get(class).cloned();
+        }
+        let mut style = style.or(&self.base);
+        if style.font.is_none() {
+            style.font = self.fonts.get("default").cloned();
+        }
+        style
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
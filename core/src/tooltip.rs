@@ -0,0 +1,225 @@
+use super::*;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+pub struct Tooltip {
+    id: Id,
+    text: String,
+}
+
+impl Tooltip {
+    #[inline]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl HasId for Tooltip {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for Tooltip {
+    fn input(&mut self, _ctx: &Context, _input: &Input, _events: &mut Events) -> ControlFlow {
+        ControlFlow::Continue
+    }
+
+    fn apply(&mut self, _funcs: &mut ApplyFuncs) {}
+
+    fn size(&self, _ctx: &LayoutContext) -> LogicalSize<f32> {
+        LogicalSize::new(0.0, 0.0)
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        SizeTypes::fix()
+    }
+
+    fn layout(&self, _lc: LayoutContext, _result: &mut LayoutConstructor) {}
+}
+
+#[derive(Debug)]
+struct Hover {
+    target: Id,
+    position: LogicalPosition<f32>,
+    since: Instant,
+}
+
+#[derive(Debug)]
+pub(crate) struct Tooltips {
+    texts: HashMap<Id, String>,
+    hover: Option<Hover>,
+    dismissed: Option<Id>,
+    widget: Tooltip,
+    pub delay: Duration,
+    pub padding: LogicalRect<f32>,
+    pub offset: LogicalPosition<f32>,
+}
+
+impl Tooltips {
+    pub(crate) fn new() -> Self {
+        Self {
+            texts: HashMap::new(),
+            hover: None,
+            dismissed: None,
+            widget: Tooltip {
+                id: Id::new(),
+                text: String::new(),
+            },
+            delay: Duration::from_millis(500),
+            padding: LogicalRect::new(5.0, 3.0, 5.0, 3.0),
+            offset: LogicalPosition::new(0.0, 20.0),
+        }
+    }
+
+    pub(crate) fn id(&self) -> Id {
+        self.widget.id
+    }
+
+    pub(crate) fn insert(&mut self, id: Id, text: String) {
+        self.texts.insert(id, text);
+    }
+
+    pub(crate) fn remove(&mut self, id: Id) {
+        self.texts.remove(&id);
+        if self.hover.as_ref().is_some_and(|hover| hover.target == id) {
+            self.hover = None;
+        }
+    }
+
+    fn find_target(&self, ctx: &Context, position: &LogicalPosition<f32>) -> Option<Id> {
+        ctx.layout
+            .hit_test(*position)
+            .into_iter()
+            .find_map(|handle| {
+                let ancestors = ctx.layout.ancestors_of(handle.id()).unwrap_or_default();
+                std::iter::once(handle.id())
+                    .chain(ancestors.iter().rev().map(|a| a.id()))
+                    .find(|id| self.texts.contains_key(id))
+            })
+    }
+
+    pub(crate) fn input(&mut self, ctx: &Context, input: &Input) {
+        if self.texts.is_empty() {
+            return;
+        }
+        match input {
+            Input::CursorMoved(m) => {
+                let position = m.mouse_state.position;
+                let Some(target) = self.find_target(ctx, &position) else {
+                    self.hover = None;
+                    self.dismissed = None;
+                    return;
+                };
+                if self.dismissed == Some(target) {
+                    return;
+                }
+                self.dismissed = None;
+                let now = ctx.now();
+                let visible = self.is_visible_at(now);
+                match self.hover.as_mut() {
+                    Some(hover) if hover.target == target => {
+                        if !visible {
+                            hover.position = position;
+                            hover.since = now;
+                        }
+                    }
+                    _ => {
+                        self.hover = Some(Hover {
+                            target,
+                            position,
+                            since: now,
+                        });
+                    }
+                }
+            }
+            Input::CursorLeft(_) => {
+                self.hover = None;
+                self.dismissed = None;
+            }
+            Input::MouseInput(_) | Input::MouseWheel(_) => {
+                if let Some(hover) = self.hover.take() {
+                    self.dismissed = Some(hover.target);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn is_visible_at(&self, now: Instant) -> bool {
+        self.hover
+            .as_ref()
+            .is_some_and(|hover| now.duration_since(hover.since) >= self.delay)
+    }
+
+    pub(crate) fn deadline(&self, ctx: &Context) -> Option<Instant> {
+        let hover = self.hover.as_ref()?;
+        let deadline = hover.since + self.delay;
+        (deadline > ctx.now()).then_some(deadline)
+    }
+
+    pub(crate) fn layout(&mut self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        if !self.is_visible_at(lc.ctx.now()) {
+            return;
+        }
+        let Some(hover) = self.hover.as_ref() else {
+            return;
+        };
+        let Some(text) = self.texts.get(&hover.target) else {
+            return;
+        };
+        let style = lc.ctx.style(&self.widget, "tooltip");
+        let Some(font) = style.font.as_ref().or(lc.ctx.default_font.as_ref()) else {
+            return;
+        };
+        self.widget.text.clone_from(text);
+        let text_rect = bounding_box_with_str(lc.ctx, font, text);
+        let size = LogicalSize::new(
+            text_rect.right + self.padding.left + self.padding.right,
+            text_rect.bottom + self.padding.top + self.padding.bottom,
+        );
+        let viewport = lc.ctx.viewport;
+        let mut position = LogicalPosition::new(
+            hover.position.x + self.offset.x,
+            hover.position.y + self.offset.y,
+        );
+        if position.x + size.width > viewport.width {
+            position.x = (viewport.width - size.width).max(0.0);
+        }
+        if position.y + size.height > viewport.height {
+            position.y = (hover.position.y - size.height).max(0.0);
+        }
+        let rect = LogicalRect::from_position_size(position, size);
+        let layer = u32::MAX;
+        result.push(
+            &lc,
+            LayoutElement::area(
+                &self.widget,
+                WidgetState::None,
+                rect,
+                &lc.ancestors,
+                layer,
+                false,
+            )
+            .with_style(&style, WidgetState::None, false),
+        );
+        result.push(
+            &lc,
+            LayoutElement::text(
+                &self.widget,
+                WidgetState::None,
+                LogicalRect::from_position_size(
+                    (rect.left + self.padding.left, rect.top + self.padding.top),
+                    text_rect.size(),
+                ),
+                &lc.ancestors,
+                Some(font.clone()),
+                text.clone(),
+                layer,
+                false,
+            )
+            .with_style(&style, WidgetState::None, false),
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use super::*;
+use std::time::{Duration, Instant};
+
+#[derive(Debug)]
+struct Point {
+    id: u64,
+    origin: LogicalPosition<f32>,
+    position: LogicalPosition<f32>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Mode {
+    None,
+    Mouse,
+    Pan,
+    Multi,
+}
+
+fn mouse_state(position: LogicalPosition<f32>, button: Option<MouseButton>) -> MouseState {
+    MouseState {
+        position,
+        buttons: button.map_or(MouseButtons::new(), |button| [button].into()),
+        modifiers: Modifiers::NONE,
+    }
+}
+
+fn mouse_input(
+    position: LogicalPosition<f32>,
+    button: MouseButton,
+    button_state: ButtonState,
+) -> Input {
+    Input::MouseInput(MouseInput {
+        button,
+        button_state,
+        click_count: 1,
+        mouse_state: mouse_state(
+            position,
+            (button_state == ButtonState::Pressed).then_some(button),
+        ),
+    })
+}
+
+#[derive(Debug)]
+pub(crate) struct Touches {
+    points: Vec<Point>,
+    mode: Mode,
+    pen_button: Option<MouseButton>,
+    velocity: (f32, f32),
+    last_move: Option<Instant>,
+    pub pan_slop: f32,
+}
+
+impl Touches {
+    pub fn new() -> Self {
+        Self {
+            points: vec![],
+            mode: Mode::None,
+            pen_button: None,
+            velocity: (0.0, 0.0),
+            last_move: None,
+            pan_slop: 8.0,
+        }
+    }
+
+    fn center(&self) -> Option<LogicalPosition<f32>> {
+        let [a, b, ..] = self.points.as_slice() else {
+            return None;
+        };
+        Some(LogicalPosition::new(
+            (a.position.x + b.position.x) / 2.0,
+            (a.position.y + b.position.y) / 2.0,
+        ))
+    }
+
+    fn distance(&self) -> Option<f32> {
+        let [a, b, ..] = self.points.as_slice() else {
+            return None;
+        };
+        Some((a.position.x - b.position.x).hypot(a.position.y - b.position.y))
+    }
+
+    fn track(&mut self, dx: f32, dy: f32, now: Instant) {
+        if let Some(last) = self.last_move {
+            let dt = now.duration_since(last).as_secs_f32();
+            if dt > 0.0 {
+                self.velocity = (
+                    dx / dt * 0.8 + self.velocity.0 * 0.2,
+                    dy / dt * 0.8 + self.velocity.1 * 0.2,
+                );
+            }
+        }
+        self.last_move = Some(now);
+    }
+
+    fn fling(&mut self, position: LogicalPosition<f32>, now: Instant) -> Option<Input> {
+        let last = self.last_move.take()?;
+        let (vx, vy) = std::mem::take(&mut self.velocity);
+        (now.duration_since(last) < Duration::from_millis(100) && vx.hypot(vy) > 50.0).then_some(
+            Input::Gesture(Gesture {
+                kind: GestureKind::Fling { vx, vy },
+                position,
+            }),
+        )
+    }
+
+    fn end_mouse(&mut self, position: LogicalPosition<f32>) -> Option<Input> {
+        (self.mode == Mode::Mouse).then(|| {
+            Input::CursorLeft(CursorLeft {
+                mouse_state: mouse_state(position, None),
+            })
+        })
+    }
+
+    pub fn touch(
+        &mut self,
+        touch: &Touch,
+        handled: bool,
+        captured: bool,
+        now: Instant,
+    ) -> Vec<Input> {
+        let mut result = vec![];
+        match touch.phase {
+            TouchPhase::Started => {
+                if handled {
+                    return result;
+                }
+                self.points.push(Point {
+                    id: touch.id,
+                    origin: touch.position,
+                    position: touch.position,
+                });
+                match self.points.len() {
+                    1 => {
+                        self.mode = Mode::Mouse;
+                        result.push(Input::CursorMoved(CursorMoved {
+                            mouse_state: mouse_state(touch.position, None),
+                        }));
+                        result.push(mouse_input(
+                            touch.position,
+                            MouseButton::Left,
+                            ButtonState::Pressed,
+                        ));
+                    }
+                    2 => {
+                        result.extend(self.end_mouse(self.points[0].position));
+                        self.mode = Mode::Multi;
+                        self.velocity = (0.0, 0.0);
+                        self.last_move = Some(now);
+                    }
+                    _ => {}
+                }
+            }
+            TouchPhase::Moved => {
+                let Some(index) = self.points.iter().position(|p| p.id == touch.id) else {
+                    return result;
+                };
+                let center = self.center();
+                let distance = self.distance();
+                let prev = std::mem::replace(&mut self.points[index].position, touch.position);
+                match self.mode {
+                    Mode::Mouse => {
+                        let origin = self.points[index].origin;
+                        let far = (touch.position.x - origin.x).abs() > self.pan_slop
+                            || (touch.position.y - origin.y).abs() > self.pan_slop;
+                        if far && !captured {
+                            result.extend(self.end_mouse(prev));
+                            self.mode = Mode::Pan;
+                            self.velocity = (0.0, 0.0);
+                            self.last_move = Some(now);
+                            result.push(Input::Gesture(Gesture {
+                                kind: GestureKind::Pan {
+                                    dx: touch.position.x - origin.x,
+                                    dy: touch.position.y - origin.y,
+                                },
+                                position: touch.position,
+                            }));
+                        } else {
+                            result.push(Input::CursorMoved(CursorMoved {
+                                mouse_state: mouse_state(touch.position, Some(MouseButton::Left)),
+                            }));
+                        }
+                    }
+                    Mode::Pan => {
+                        self.track(touch.position.x - prev.x, touch.position.y - prev.y, now);
+                        result.push(Input::Gesture(Gesture {
+                            kind: GestureKind::Pan {
+                                dx: touch.position.x - prev.x,
+                                dy: touch.position.y - prev.y,
+                            },
+                            position: touch.position,
+                        }));
+                    }
+                    Mode::Multi => {
+                        let (Some(prev_center), Some(center)) = (center, self.center()) else {
+                            return result;
+                        };
+                        if prev_center != center {
+                            self.track(center.x - prev_center.x, center.y - prev_center.y, now);
+                            result.push(Input::Gesture(Gesture {
+                                kind: GestureKind::Scroll {
+                                    dx: center.x - prev_center.x,
+                                    dy: center.y - prev_center.y,
+                                },
+                                position: center,
+                            }));
+                        }
+                        if let (Some(prev_distance), Some(distance)) = (distance, self.distance())
+                            && prev_distance > 0.0
+                            && prev_distance != distance
+                        {
+                            result.push(Input::Gesture(Gesture {
+                                kind: GestureKind::Pinch {
+                                    scale: distance / prev_distance,
+                                },
+                                position: center,
+                            }));
+                        }
+                    }
+                    Mode::None => {}
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                let Some(index) = self.points.iter().position(|p| p.id == touch.id) else {
+                    return result;
+                };
+                let center = self.center();
+                self.points.remove(index);
+                match self.mode {
+                    Mode::Pan if touch.phase == TouchPhase::Ended => {
+                        result.extend(self.fling(touch.position, now));
+                    }
+                    Mode::Multi if self.points.len() == 1 && touch.phase == TouchPhase::Ended => {
+                        result.extend(center.and_then(|center| self.fling(center, now)));
+                    }
+                    _ => {}
+                }
+                if self.mode == Mode::Mouse {
+                    if touch.phase == TouchPhase::Ended {
+                        result.push(mouse_input(
+                            touch.position,
+                            MouseButton::Left,
+                            ButtonState::Released,
+                        ));
+                    } else {
+                        result.extend(self.end_mouse(touch.position));
+                    }
+                }
+                if self.points.is_empty() {
+                    self.mode = Mode::None;
+                    self.last_move = None;
+                }
+            }
+        }
+        result
+    }
+
+    pub fn pen(&mut self, pen: &PenInput, handled: bool) -> Vec<Input> {
+        match pen.phase {
+            TouchPhase::Started => {
+                if handled {
+                    return vec![];
+                }
+                let button = if pen.barrel_button {
+                    MouseButton::Right
+                } else {
+                    MouseButton::Left
+                };
+                self.pen_button = Some(button);
+                vec![
+                    Input::CursorMoved(CursorMoved {
+                        mouse_state: mouse_state(pen.position, None),
+                    }),
+                    mouse_input(pen.position, button, ButtonState::Pressed),
+                ]
+            }
+            TouchPhase::Moved => {
+                if handled {
+                    return vec![];
+                }
+                vec![Input::CursorMoved(CursorMoved {
+                    mouse_state: mouse_state(pen.position, self.pen_button),
+                })]
+            }
+            TouchPhase::Ended => self
+                .pen_button
+                .take()
+                .map(|button| mouse_input(pen.position, button, ButtonState::Released))
+                .into_iter()
+                .collect(),
+            TouchPhase::Cancelled => {
+                self.pen_button = None;
+                vec![Input::CursorLeft(CursorLeft {
+                    mouse_state: mouse_state(pen.position, None),
+                })]
+            }
+        }
+    }
+}
@@ -43,11 +43,45 @@ impl SizeTypes {
 }
 
 pub trait Widget: Any + HasId {
+    /// Only widgets that push an event are relaid out after input, so a widget that changes
+    /// how it looks without pushing one must call `Context::invalidate` on itself.
     fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow;
     fn apply(&mut self, funcs: &mut ApplyFuncs);
     fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32>;
     fn size_types(&self) -> SizeTypes;
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor);
+
+    fn on_input(&mut self, _ctx: &Context, _input: &mut RoutedInput, _events: &mut Events) {}
+
+    fn on_drag(&mut self, _ctx: &Context, _drag: &mut DragEvent, _events: &mut Events) {}
+
+    fn baseline(&self, _lc: &LayoutContext) -> Option<f32> {
+        None
+    }
+
+    fn size_hint(&self, lc: &LayoutContext) -> SizeHint {
+        SizeHint::new(self.size(lc), self.size_types())
+    }
+
+    fn measure(&self, lc: &LayoutContext, constraints: Constraints) -> LogicalSize<f32> {
+        let available = constraints.available(lc.rect.size());
+        let lc = lc.with_rect(LogicalRect::from_position_size(
+            lc.rect.left_top(),
+            available,
+        ));
+        let size = self.size(&lc);
+        let size_types = self.size_types();
+        constraints.constrain(LogicalSize::new(
+            match size_types.width {
+                SizeType::Fix => size.width,
+                SizeType::Flexible => available.width,
+            },
+            match size_types.height {
+                SizeType::Fix => size.height,
+                SizeType::Flexible => available.height,
+            },
+        ))
+    }
 }
 
 pub trait WidgetMessage: Widget {
@@ -122,7 +156,7 @@ where
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct AnyHandle {
     id: Id,
     t: TypeId,
@@ -218,10 +252,12 @@ where
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
 pub enum WidgetState {
     None,
     Hover,
     Pressed,
+    Disabled,
 }
 
 impl WidgetState {
@@ -15,12 +15,12 @@ fn create_scene() -> glane::Scene {
     scene.push_child(&row_text_box, glane::widgets::TextBox::new());
     let row_scroll_bar = scene.push_child(&left, glane::widgets::Row::new());
     scene.push_child(&row_scroll_bar, glane::widgets::Label::new("ScrollBar"));
-    let scroll_bar = glane::widgets::VScrollBar::new(100, 10);
+    let scroll_bar = glane::widgets::VScrollBar::new(100.0, 10.0);
     scene.push_child(
         &row_scroll_bar,
         glane::widgets::MaxSize::new(None, Some(200.0), scroll_bar),
     );
-    let scroll_bar = glane::widgets::VScrollBar::new(1000, 10);
+    let scroll_bar = glane::widgets::VScrollBar::new(1000.0, 10.0);
     scene.push_child(
         &row_scroll_bar,
         glane::widgets::MaxSize::new(None, Some(200.0), scroll_bar),
@@ -82,9 +82,11 @@ fn input(bencher: divan::Bencher) {
             let mouse_input = glane::Input::MouseInput(glane::MouseInput {
                 button: glane::MouseButton::Left,
                 button_state: glane::ButtonState::Pressed,
+                click_count: 1,
                 mouse_state: glane::MouseState {
                     position: (0.0, 0.0).into(),
                     buttons: [glane::MouseButton::Left].into(),
+                    modifiers: glane::Modifiers::NONE,
                 },
             });
             (create_scene(), mouse_input)
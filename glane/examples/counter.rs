@@ -27,6 +27,7 @@ fn mouse_state(m: &wiard::MouseState, dpi: f32) -> glane::MouseState {
     glane::MouseState {
         position,
         buttons: mouse_buttons(&m.buttons),
+        modifiers: glane::Modifiers::NONE,
     }
 }
 
@@ -42,6 +43,7 @@ fn mouse_input(m: &wiard::event::MouseInput, dpi: f32) -> glane::MouseInput {
             wiard::ButtonState::Pressed => glane::ButtonState::Pressed,
             wiard::ButtonState::Released => glane::ButtonState::Released,
         },
+        click_count: 1,
         mouse_state: mouse_state(&m.mouse_state, dpi),
     }
 }
@@ -93,9 +95,9 @@ impl Canvas {
             glane::LayoutElement::Area(area) => match l.handle().type_id() {
                 t if t == TypeId::of::<glane::widgets::Button>() => {
                     let brush = match area.widget_state {
-                        glane::WidgetState::None => &self.button_bg,
                         glane::WidgetState::Hover => &self.button_bg_hover,
                         glane::WidgetState::Pressed => &self.button_bg_pressed,
+                        _ => &self.button_bg,
                     };
                     let rect = l.rect();
                     cmd.fill(
@@ -2,6 +2,8 @@ use glane::ToLogical;
 use std::any::TypeId;
 use std::cell::Cell;
 use std::rc::Rc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::Instant;
 
 fn mouse_buttons(src: &wiard::MouseButtons) -> glane::MouseButtons {
     let mut dest = glane::MouseButtons::new();
@@ -29,6 +31,7 @@ fn mouse_state(m: &wiard::MouseState, dpi: f32) -> glane::MouseState {
     glane::MouseState {
         position,
         buttons: mouse_buttons(&m.buttons),
+        modifiers: glane::Modifiers::NONE,
     }
 }
 
@@ -44,6 +47,7 @@ fn mouse_input(m: &wiard::event::MouseInput, dpi: f32) -> glane::MouseInput {
             wiard::ButtonState::Pressed => glane::ButtonState::Pressed,
             wiard::ButtonState::Released => glane::ButtonState::Released,
         },
+        click_count: 1,
         mouse_state: mouse_state(&m.mouse_state, dpi),
     }
 }
@@ -54,7 +58,7 @@ fn mouse_wheel(m: &wiard::event::MouseWheel, dpi: f32) -> glane::MouseWheel {
             wiard::MouseWheelAxis::Vertical => glane::MouseWheelAxis::Vertical,
             wiard::MouseWheelAxis::Horizontal => glane::MouseWheelAxis::Horizontal,
         },
-        distance: -m.distance / wiard::WHEEL_DELTA,
+        distance: -m.distance as f32 / wiard::WHEEL_DELTA as f32,
         mouse_state: mouse_state(&m.mouse_state, dpi),
     }
 }
@@ -64,16 +68,8 @@ struct Canvas {
     render_target: pnte::d2d1::RenderTarget,
     text_format: pnte::TextFormat,
     white: pnte::SolidColorBrush,
-    button_bg: pnte::SolidColorBrush,
-    button_bg_hover: pnte::SolidColorBrush,
-    button_bg_pressed: pnte::SolidColorBrush,
-    text_box_border: pnte::SolidColorBrush,
-    scroll_bar_bg: pnte::SolidColorBrush,
-    scroll_bar_thumb: pnte::SolidColorBrush,
-    selected_bg: pnte::SolidColorBrush,
-    list_box_bg: pnte::SolidColorBrush,
-    slider_bg: pnte::SolidColorBrush,
-    slider_knob: pnte::SolidColorBrush,
+    border: pnte::SolidColorBrush,
+    background: (f32, f32, f32, f32),
 }
 
 impl Canvas {
@@ -93,163 +89,225 @@ impl Canvas {
             ))
             .size(font_size)
             .build()?;
-        let white = pnte::SolidColorBrush::new(&ctx, (1.0, 1.0, 1.0, 1.0))?;
-        let button_bg = pnte::SolidColorBrush::new(&ctx, (0.3, 0.3, 0.3, 1.0))?;
-        let button_bg_hover = pnte::SolidColorBrush::new(&ctx, (0.4, 0.4, 0.4, 1.0))?;
-        let button_bg_pressed = pnte::SolidColorBrush::new(&ctx, (0.6, 0.6, 0.6, 1.0))?;
-        let text_box_border = pnte::SolidColorBrush::new(&ctx, (0.3, 0.3, 0.3, 1.0))?;
-        let scroll_bar_bg = pnte::SolidColorBrush::new(&ctx, (0.3, 0.3, 0.3, 1.0))?;
-        let scroll_bar_thumb = pnte::SolidColorBrush::new(&ctx, (0.8, 0.8, 0.8, 1.0))?;
-        let selected_bg = pnte::SolidColorBrush::new(&ctx, (0.0, 0.3, 0.0, 1.0))?;
-        let list_box_bg = pnte::SolidColorBrush::new(&ctx, (0.1, 0.1, 0.1, 0.9))?;
-        let slider_bg = pnte::SolidColorBrush::new(&ctx, (0.3, 0.3, 0.3, 1.0))?;
-        let slider_knob = pnte::SolidColorBrush::new(&ctx, (0.8, 0.8, 0.8, 1.0))?;
+        let theme = scene.theme();
+        let color = |name: &str| -> (f32, f32, f32, f32) {
+            theme.color(name).unwrap_or(glane::Color::WHITE).into()
+        };
+        let white = pnte::SolidColorBrush::new(&ctx, color("foreground"))?;
+        let border = pnte::SolidColorBrush::new(&ctx, color("border"))?;
+        let background = color("background");
         Ok(Self {
             ctx,
             render_target,
             text_format,
             white,
-            button_bg,
-            button_bg_hover,
-            button_bg_pressed,
-            text_box_border,
-            scroll_bar_bg,
-            scroll_bar_thumb,
-            selected_bg,
-            list_box_bg,
-            slider_bg,
-            slider_knob,
+            border,
+            background,
         })
     }
 
-    fn draw_element<T: pnte::Backend>(&self, cmd: &pnte::DrawCommand<T>, l: &glane::LayoutElement) {
-        match l {
-            glane::LayoutElement::Area(area) => {
-                let rect = area.rect;
-                let rect = pnte::Rect::new(rect.left, rect.top, rect.right, rect.bottom);
-                if area.selected {
-                    let rect = pnte::Rect::new(
-                        area.rect.left,
-                        area.rect.top,
-                        area.rect.right,
-                        area.rect.bottom,
+    fn brush(&self, color: Option<glane::Color>) -> Option<pnte::SolidColorBrush> {
+        let color: (f32, f32, f32, f32) = color?.into();
+        pnte::SolidColorBrush::new(&self.ctx, color).ok()
+    }
+
+    fn fill_area<T: pnte::Backend>(
+        &self,
+        cmd: &pnte::DrawCommand<T>,
+        rect: pnte::Rect<f32>,
+        radius: f32,
+        brush: &pnte::SolidColorBrush,
+    ) {
+        if radius > 0.0 {
+            cmd.fill(&pnte::RoundedRect::new(rect, radius, radius), brush);
+        } else {
+            cmd.fill(&rect, brush);
+        }
+    }
+
+    fn draw_area<T: pnte::Backend>(&self, cmd: &pnte::DrawCommand<T>, area: &glane::layout::Area) {
+        let r = area.rect;
+        let rect = pnte::Rect::new(r.left, r.top, r.right, r.bottom);
+        let size = r.size();
+        let opacity = |color: glane::Color| color.with_alpha(color.a * area.opacity);
+        if let Some(shadow) = area.shadow.as_ref()
+            && let Some(brush) = self.brush(Some(opacity(shadow.color)))
+        {
+            let spread = shadow.spread + shadow.blur / 2.0;
+            let rect = pnte::Rect::new(
+                r.left + shadow.offset.x - spread,
+                r.top + shadow.offset.y - spread,
+                r.right + shadow.offset.x + spread,
+                r.bottom + shadow.offset.y + spread,
+            );
+            self.fill_area(cmd, rect, area.radius + spread, &brush);
+        }
+        let fill = self.brush(area.fill.map(opacity));
+        let stroke = self.brush(area.stroke.map(opacity));
+        match area.handle.type_id() {
+            t if t == TypeId::of::<glane::widgets::Slider>() => {
+                if let Some(brush) = fill.as_ref() {
+                    cmd.fill(
+                        &pnte::Rect::from_point_size(
+                            (r.left, r.top + size.height / 4.0),
+                            (size.width, size.height / 2.0),
+                        ),
+                        brush,
                     );
-                    cmd.fill(&rect, &self.selected_bg);
-                    return;
                 }
-                match l.handle().type_id() {
-                    t if t == TypeId::of::<glane::widgets::Button>() => {
-                        let brush = match area.widget_state {
-                            glane::WidgetState::None => &self.button_bg,
-                            glane::WidgetState::Hover => &self.button_bg_hover,
-                            glane::WidgetState::Pressed => &self.button_bg_pressed,
-                        };
-                        let rect = l.rect();
-                        cmd.fill(
-                            &pnte::Rect::new(rect.left, rect.top, rect.right, rect.bottom),
-                            brush,
-                        );
-                    }
-                    t if t == TypeId::of::<glane::widgets::VScrollBar>()
-                        || t == TypeId::of::<glane::widgets::HScrollBar>() =>
-                    {
-                        let rect = l.rect();
-                        cmd.fill(
-                            &pnte::Rect::new(rect.left, rect.top, rect.right, rect.bottom),
-                            &self.scroll_bar_bg,
-                        );
-                    }
-                    t if t == TypeId::of::<glane::widgets::scroll_bar::Thumb>() => {
-                        let rect = l.rect();
-                        cmd.fill(
-                            &pnte::Rect::new(rect.left, rect.top, rect.right, rect.bottom),
-                            &self.scroll_bar_thumb,
-                        );
-                    }
-                    t if t == TypeId::of::<glane::widgets::ListBox>() => {
-                        cmd.fill(
-                            &pnte::Rect::new(rect.left, rect.top, rect.right, rect.bottom),
-                            &self.list_box_bg,
-                        );
-                        cmd.stroke(
-                            &pnte::Rect::new(rect.left, rect.top, rect.right, rect.bottom),
-                            &self.text_box_border,
-                            2.0,
-                            None,
-                        );
-                    }
-                    t if t == TypeId::of::<glane::widgets::TextBox>() => {
-                        let rect = l.rect();
-                        cmd.stroke(
-                            &pnte::Rect::new(rect.left, rect.top, rect.right, rect.bottom),
-                            &self.text_box_border,
-                            2.0,
-                            None,
-                        );
-                    }
-                    t if t == TypeId::of::<glane::widgets::Slider>() => {
-                        let rect = l.rect();
-                        let size = rect.size();
-                        cmd.fill(
-                            &pnte::Rect::from_point_size(
-                                (rect.left, rect.top + size.height / 4.0),
-                                (size.width, size.height / 2.0),
-                            ),
-                            &self.slider_bg,
-                        );
-                    }
-                    t if t == TypeId::of::<glane::widgets::slider::Knob>() => {
-                        let rect = l.rect();
-                        let size = rect.size();
-                        cmd.fill(
-                            &pnte::Circle::new(
-                                (rect.left + size.width / 2.0, rect.top + size.height / 2.0),
-                                size.width / 2.0,
-                            ),
-                            &self.slider_knob,
-                        );
-                    }
-                    t if t == TypeId::of::<glane::widgets::CheckBox>() => {
-                        let rect = l.rect();
-                        let size = rect.size();
-                        cmd.fill(
-                            &pnte::Rect::from_point_size(
-                                (rect.left, rect.top),
-                                (size.width, size.height),
-                            ),
-                            &self.button_bg,
-                        );
-                    }
-                    t if t == TypeId::of::<glane::widgets::check_box::Check>() => {
-                        let rect = l.rect();
-                        let size = rect.size();
-                        cmd.fill(
-                            &pnte::Rect::from_point_size(
-                                (rect.left, rect.top),
-                                (size.width, size.height),
-                            ),
-                            &self.selected_bg,
-                        );
+                return;
+            }
+            t if t == TypeId::of::<glane::widgets::slider::Knob>() => {
+                if let Some(brush) = fill.as_ref() {
+                    cmd.fill(
+                        &pnte::Circle::new(
+                            (r.left + size.width / 2.0, r.top + size.height / 2.0),
+                            size.width / 2.0,
+                        ),
+                        brush,
+                    );
+                }
+                return;
+            }
+            _ => {}
+        }
+        if let Some(brush) = fill.as_ref() {
+            self.fill_area(cmd, rect, area.radius, brush);
+        }
+        if area.selected {
+            return;
+        }
+        match stroke.as_ref() {
+            Some(brush) if area.stroke_width > 0.0 => {
+                if area.radius > 0.0 {
+                    cmd.stroke(
+                        &pnte::RoundedRect::new(rect, area.radius, area.radius),
+                        brush,
+                        area.stroke_width,
+                        None,
+                    );
+                } else {
+                    cmd.stroke(&rect, brush, area.stroke_width, None);
+                }
+            }
+            None if fill.is_none() => cmd.stroke(&rect, &self.border, 2.0, None),
+            _ => {}
+        }
+    }
+
+    fn draw_image<T: pnte::Backend>(
+        &self,
+        cmd: &pnte::DrawCommand<T>,
+        image: &glane::layout::Image,
+    ) {
+        let glane::ImageSource::Rgba(data) = &image.source else {
+            let r = image.rect;
+            cmd.stroke(
+                &pnte::Rect::new(r.left, r.top, r.right, r.bottom),
+                &self.border,
+                1.0,
+                None,
+            );
+            return;
+        };
+        let r = image.rect;
+        let dest = image.image_rect();
+        let scale_x = dest.size().width / data.width() as f32;
+        let scale_y = dest.size().height / data.height() as f32;
+        cmd.push_clip(pnte::Rect::new(r.left, r.top, r.right, r.bottom));
+        for (i, px) in data.data().chunks_exact(4).enumerate() {
+            if px[3] == 0 {
+                continue;
+            }
+            let x = (i % data.width() as usize) as f32;
+            let y = (i / data.width() as usize) as f32;
+            let [r, g, b, a] = [px[0], px[1], px[2], px[3]].map(|v| v as f32 / 255.0);
+            let color = glane::Color::new(r, g, b, a);
+            let Some(brush) = self.brush(Some(color.with_alpha(color.a * image.opacity))) else {
+                continue;
+            };
+            cmd.fill(
+                &pnte::Rect::from_point_size(
+                    (dest.left + x * scale_x, dest.top + y * scale_y),
+                    (scale_x, scale_y),
+                ),
+                &brush,
+            );
+        }
+        cmd.pop_clip();
+    }
+
+    fn build_path(&self, path: &glane::Path) -> Vec<pnte::Path> {
+        let mut paths = vec![];
+        let mut builder: Option<pnte::PathBuilder> = None;
+        for command in path.commands() {
+            let point = |p: &glane::LogicalPosition<f32>| (p.x, p.y);
+            builder = match command {
+                glane::PathCommand::MoveTo(p) => {
+                    if let Some(b) = builder.take() {
+                        paths.extend(b.build(pnte::PathEnd::Open).ok());
                     }
-                    _ => {
-                        let rect = l.rect();
-                        cmd.stroke(
-                            &pnte::Rect::new(rect.left, rect.top, rect.right, rect.bottom),
-                            &self.text_box_border,
-                            2.0,
-                            None,
-                        );
+                    pnte::Path::builder(&self.ctx, point(p)).ok()
+                }
+                glane::PathCommand::LineTo(p) => builder.map(|b| b.line_to(point(p))),
+                glane::PathCommand::QuadTo(c, p) => {
+                    builder.map(|b| b.quadratic_bezier_to(point(c), point(p)))
+                }
+                glane::PathCommand::CubicTo(c0, c1, p) => {
+                    builder.map(|b| b.cubic_bezier_to(point(c0), point(c1), point(p)))
+                }
+                glane::PathCommand::Close => {
+                    if let Some(b) = builder.take() {
+                        paths.extend(b.build(pnte::PathEnd::Closed).ok());
                     }
+                    None
                 }
+            };
+        }
+        if let Some(b) = builder {
+            paths.extend(b.build(pnte::PathEnd::Open).ok());
+        }
+        paths
+    }
+
+    fn draw_path<T: pnte::Backend>(
+        &self,
+        cmd: &pnte::DrawCommand<T>,
+        shape: &glane::layout::Shape,
+    ) {
+        let opacity = |color: glane::Color| color.with_alpha(color.a * shape.opacity);
+        let fill = self.brush(shape.fill.map(opacity));
+        let stroke = self.brush(shape.stroke.map(opacity));
+        for path in self.build_path(&shape.path) {
+            if let Some(brush) = fill.as_ref() {
+                cmd.fill(&path, brush);
+            }
+            if let Some(brush) = stroke.as_ref()
+                && shape.stroke_width > 0.0
+            {
+                cmd.stroke(&path, brush, shape.stroke_width, None);
             }
+        }
+    }
+
+    fn draw_element<T: pnte::Backend>(&self, cmd: &pnte::DrawCommand<T>, l: &glane::LayoutElement) {
+        match l {
+            glane::LayoutElement::Area(area) => self.draw_area(cmd, area),
+            glane::LayoutElement::Image(image) => self.draw_image(cmd, image),
+            glane::LayoutElement::Path(shape) => self.draw_path(cmd, shape),
             glane::LayoutElement::Text(t) => {
                 let text = pnte::TextLayout::new(&self.ctx)
                     .text(&t.string)
                     .format(&self.text_format)
                     .build()
                     .unwrap();
-                cmd.draw_text(&text, (l.rect().left, l.rect().top), &self.white)
-                    .unwrap();
+                let brush = self.brush(t.color.map(|c| c.with_alpha(c.a * t.opacity)));
+                cmd.draw_text(
+                    &text,
+                    (l.rect().left, l.rect().top),
+                    brush.as_ref().unwrap_or(&self.white),
+                )
+                .unwrap();
             }
             glane::LayoutElement::CompositionText(t) => {
                 let text = pnte::TextLayout::new(&self.ctx)
@@ -295,7 +353,7 @@ impl Canvas {
 
     fn draw(&self, layout: &glane::Layout) -> anyhow::Result<()> {
         self.ctx.draw(&self.render_target, |cmd| {
-            cmd.clear((0.0, 0.0, 0.3, 0.0));
+            cmd.clear(self.background);
             for l in layout.iter() {
                 self.draw_element(&cmd, l);
             }
@@ -309,6 +367,23 @@ impl Canvas {
     }
 }
 
+fn icon(size: u32) -> glane::RgbaImage {
+    let center = size as f32 / 2.0;
+    let data = (0..size * size)
+        .flat_map(|i| {
+            let dx = (i % size) as f32 + 0.5 - center;
+            let dy = (i / size) as f32 + 0.5 - center;
+            let alpha = if dx * dx + dy * dy <= center * center {
+                255
+            } else {
+                0
+            };
+            [0x3d, 0x8e, 0xe6, alpha]
+        })
+        .collect();
+    glane::RgbaImage::new(size, size, data).unwrap()
+}
+
 fn main() -> anyhow::Result<()> {
     pnte::co_initialize(pnte::CoInit::ApartmentThreaded)?;
     let mut event_rx = wiard::EventReceiver::new();
@@ -323,16 +398,26 @@ fn main() -> anyhow::Result<()> {
         );
         (glane::Scene::new(root), left, right)
     };
-    let row_button = scene.push_child(&left, glane::widgets::Row::new());
+    let row_button = scene.push_child(&left, {
+        let mut row = glane::widgets::Row::new();
+        row.align_items = glane::widgets::flex::AlignItems::Baseline;
+        row
+    });
     scene.push_child(&row_button, glane::widgets::Label::new("Button"));
-    let button = scene.push_child(&row_button, glane::widgets::Button::new("Push"));
+    let button = scene.push_child(
+        &row_button,
+        glane::widgets::Button::new("Push").with_icon(icon(16)),
+    );
+    scene.set_tooltip(&button, "Prints a message");
+    let disabled_button = scene.push_child(&row_button, glane::widgets::Button::new("Disabled"));
+    scene.set_enabled(&disabled_button, false);
     let row_text_box = scene.push_child(&left, glane::widgets::Row::new());
     scene.push_child(&row_text_box, glane::widgets::Label::new("TextBox"));
     let text_box = scene.push_child(&row_text_box, glane::widgets::TextBox::new());
     let row_scroll_bar = scene.push_child(&left, glane::widgets::Row::new());
     scene.push_child(&row_scroll_bar, glane::widgets::Label::new("ScrollBar"));
     let scroll_bar = {
-        let scroll_bar = glane::widgets::VScrollBar::new(100, 10);
+        let scroll_bar = glane::widgets::VScrollBar::new(100.0, 10.0);
         let handle = glane::Handle::new(&scroll_bar);
         scene.push_child(
             &row_scroll_bar,
@@ -341,7 +426,7 @@ fn main() -> anyhow::Result<()> {
         handle
     };
     let scroll_bar2 = {
-        let scroll_bar = glane::widgets::VScrollBar::new(1000, 10);
+        let scroll_bar = glane::widgets::VScrollBar::new(1000.0, 10.0);
         let handle = glane::Handle::new(&scroll_bar);
         scene.push_child(
             &row_scroll_bar,
@@ -352,7 +437,8 @@ fn main() -> anyhow::Result<()> {
     let row_slider = scene.push_child(&left, glane::widgets::Row::new());
     scene.push_child(&row_slider, glane::widgets::Label::new("Slider"));
     let slider = {
-        let slider = glane::widgets::Slider::new();
+        let mut slider = glane::widgets::Slider::new();
+        slider.ticks = 5;
         let handle = glane::Handle::new(&slider);
         scene.push_child(
             &row_slider,
@@ -361,6 +447,101 @@ fn main() -> anyhow::Result<()> {
         handle
     };
     let check_box = scene.push_child(&left, glane::widgets::CheckBox::new("CheckBox", false));
+    let row_canvas = scene.push_child(&left, glane::widgets::Row::new());
+    scene.push_child(&row_canvas, glane::widgets::Label::new("Canvas"));
+    {
+        use glane::widgets::canvas::Shape;
+        let mut canvas = glane::widgets::Canvas::new((64.0, 64.0))
+            .with_view_box(glane::LogicalRect::new(0.0, 0.0, 1.0, 1.0));
+        canvas.push(
+            Shape::new(
+                glane::Path::new()
+                    .move_to((0.5, 0.05))
+                    .line_to((0.95, 0.9))
+                    .line_to((0.05, 0.9))
+                    .close(),
+            )
+            .fill(glane::Color::rgb(0.24, 0.56, 0.9))
+            .stroke(glane::Color::WHITE, 2.0),
+        );
+        canvas.push(
+            Shape::new(
+                glane::Path::new()
+                    .move_to((0.3, 0.7))
+                    .quad_to((0.5, 0.3), (0.7, 0.7)),
+            )
+            .stroke(glane::Color::WHITE, 2.0),
+        );
+        scene.push_child(&row_canvas, canvas);
+    }
+    {
+        use glane::widgets::StackItem;
+        use glane::widgets::align::Alignment;
+        let mut stack = glane::widgets::Stack::new();
+        stack.push(glane::widgets::Image::new(icon(32)));
+        stack.push_with(
+            glane::widgets::Label::new("3"),
+            StackItem::new(Alignment::End, Alignment::Start).offset(4.0, -4.0),
+        );
+        scene.push_child(&row_canvas, stack);
+    }
+    let context_menu = {
+        use glane::widgets::menu::Item;
+        let mut menu = glane::widgets::Menu::new();
+        menu.push(Item::new(0, "&Copy").accelerator("Ctrl+C"));
+        menu.push(Item::new(1, "&Paste").accelerator("Ctrl+V"));
+        menu.push(Item::separator());
+        menu.push(Item::check(2, "&Wrap", false));
+        let mut more = glane::widgets::Menu::new();
+        more.push(Item::new(3, "&First"));
+        more.push(Item::new(4, "&Second"));
+        menu.push(Item::submenu("&More", more));
+        scene.push_child(&left, menu)
+    };
+    let flex = {
+        use glane::widgets::flex::{AlignItems, Justify};
+        let mut flex = glane::widgets::Flex::row();
+        flex.justify_content = Justify::SpaceBetween;
+        flex.align_items = AlignItems::Center;
+        flex.push(glane::widgets::Label::new("Flex"));
+        flex.push_with(
+            glane::widgets::Slider::new(),
+            glane::widgets::FlexItem::new().grow(1.0),
+        );
+        flex.push(glane::widgets::Button::new("End"));
+        flex
+    };
+    scene.push_child(&right, flex);
+    let grid = {
+        use glane::widgets::grid::{Align, Placement, Track};
+        let mut grid = glane::widgets::Grid::new(
+            [Track::Auto, Track::Fraction(1.0)],
+            [Track::Auto, Track::Auto, Track::Auto],
+        );
+        grid.push(glane::widgets::Label::new("Name"));
+        grid.push(glane::widgets::TextBox::new());
+        grid.push(glane::widgets::Label::new("E-mail address"));
+        grid.push(glane::widgets::TextBox::new());
+        grid.place(
+            glane::widgets::Button::new("Submit"),
+            Placement::new(2, 0)
+                .span(1, 2)
+                .align(Align::End, Align::Start),
+        );
+        grid
+    };
+    scene.push_child(&right, grid);
+    scene.push_child(
+        &right,
+        glane::widgets::SizedBox::new(
+            None,
+            Some(48.0),
+            glane::widgets::Align::center(glane::widgets::Padding::all(
+                4.0,
+                glane::widgets::Label::new("Centered"),
+            )),
+        ),
+    );
     let row_dropdown_box = scene.push_child(&right, glane::widgets::Row::new());
     scene.push_child(&row_dropdown_box, glane::widgets::Label::new("DropdownBox"));
     let dropdown_box = scene.push_child(&row_dropdown_box, glane::widgets::DropdownBox::new());
@@ -370,7 +551,8 @@ fn main() -> anyhow::Result<()> {
     let row_list_box = scene.push_child(&right, glane::widgets::Row::new());
     scene.push_child(&row_list_box, glane::widgets::Label::new("ListBox"));
     let list_box = {
-        let list_box = glane::widgets::ListBox::new();
+        let mut list_box = glane::widgets::ListBox::new();
+        list_box.reorderable = true;
         let handle = glane::Handle::new(&list_box);
         scene.push_child(&row_list_box, list_box);
         handle
@@ -411,8 +593,41 @@ fn main() -> anyhow::Result<()> {
             redrawing.set(true);
         }
     };
+    let (deadline_tx, deadline_rx) = std::sync::mpsc::channel::<Instant>();
+    std::thread::spawn({
+        let window = window.clone();
+        move || {
+            let mut deadline = None;
+            loop {
+                let received = match deadline {
+                    Some(deadline) => {
+                        deadline_rx.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                    }
+                    None => deadline_rx
+                        .recv()
+                        .map_err(|_| RecvTimeoutError::Disconnected),
+                };
+                match received {
+                    Ok(d) => deadline = Some(d),
+                    Err(RecvTimeoutError::Timeout) => {
+                        deadline = None;
+                        window.post_app_event(wiard::event::App::new(0, 0, 0));
+                    }
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+        }
+    });
     let mut events = glane::Events::new();
+    let mut sent_deadline = None;
     loop {
+        let deadline = scene.next_deadline();
+        if deadline != sent_deadline {
+            if let Some(deadline) = deadline {
+                deadline_tx.send(deadline).ok();
+            }
+            sent_deadline = deadline;
+        }
         let Some((event, _)) = event_rx.recv() else {
             break;
         };
@@ -423,7 +638,13 @@ fn main() -> anyhow::Result<()> {
                     continue;
                 };
                 let input = mouse_input(&m, dpi as f32);
+                let context_menu_position = (input.button == glane::MouseButton::Right
+                    && input.button_state == glane::ButtonState::Released)
+                    .then_some(input.mouse_state.position);
                 scene.input(glane::Input::MouseInput(input), &mut events);
+                if let Some(position) = context_menu_position {
+                    scene.apply(&context_menu, move |menu| menu.open(position));
+                }
                 redraw(&window);
             }
             wiard::Event::CursorMoved(m) => {
@@ -454,7 +675,10 @@ fn main() -> anyhow::Result<()> {
                 scene.input(
                     glane::Input::KeyInput(glane::KeyInput {
                         vkey: ev.key_code.vkey,
+                        scan_code: ev.key_code.scan_code,
                         key_state: ev.key_state,
+                        modifiers: glane::Modifiers::NONE,
+                        repeat: false,
                     }),
                     &mut events,
                 );
@@ -498,6 +722,15 @@ fn main() -> anyhow::Result<()> {
                 scene.input(glane::Input::ImeEndComposition(ev.result), &mut events);
                 redraw(&window);
             }
+            wiard::Event::Inactivate => {
+                scene.input(glane::Input::FocusLost, &mut events);
+                redraw(&window);
+            }
+            wiard::Event::App(_) => {
+                sent_deadline = None;
+                scene.input(glane::Input::Tick, &mut events);
+                redraw(&window);
+            }
             wiard::Event::Draw(_) => {
                 let layout = scene.layout();
                 canvas.draw(&layout)?;
@@ -530,13 +763,13 @@ fn main() -> anyhow::Result<()> {
             } else if let Some(msg) = event.message(&scroll_bar) {
                 match msg {
                     glane::widgets::scroll_bar::Message::Changed(p) => {
-                        println!("scrollbar changed: {p}-{}", p + 10);
+                        println!("scrollbar changed: {p}-{}", p + 10.0);
                     }
                 }
             } else if let Some(msg) = event.message(&scroll_bar2) {
                 match msg {
                     glane::widgets::scroll_bar::Message::Changed(p) => {
-                        println!("scrollbar2 changed: {p}-{}", p + 10);
+                        println!("scrollbar2 changed: {p}-{}", p + 10.0);
                     }
                 }
             } else if let Some(msg) = event.message(&list_box) {
@@ -544,6 +777,12 @@ fn main() -> anyhow::Result<()> {
                     glane::widgets::list_box::Message::Selected(i) => {
                         println!("list_box selected: {i}");
                     }
+                    glane::widgets::list_box::Message::Activated(i) => {
+                        println!("list_box activated: {i}");
+                    }
+                    glane::widgets::list_box::Message::Moved { from, to } => {
+                        println!("list_box moved: {from} -> {to}");
+                    }
                 }
             } else if let Some(msg) = event.message(&dropdown_box) {
                 match msg {
@@ -569,6 +808,15 @@ fn main() -> anyhow::Result<()> {
                         println!("check box: {b}");
                     }
                 }
+            } else if let Some(msg) = event.message(&context_menu) {
+                match msg {
+                    glane::widgets::menu::Message::Activated(command) => {
+                        println!("context menu activated: {command}");
+                    }
+                    glane::widgets::menu::Message::Closed => {
+                        println!("context menu closed");
+                    }
+                }
             } else {
                 for (i, inner_button) in inner_buttons.iter().enumerate() {
                     if let Some(msg) = event.message(inner_button) {
@@ -30,6 +30,7 @@ fn mouse_state(m: &wiard::MouseState, dpi: f32) -> glane::MouseState {
     glane::MouseState {
         position,
         buttons: mouse_buttons(&m.buttons),
+        modifiers: glane::Modifiers::NONE,
     }
 }
 
@@ -45,6 +46,7 @@ fn mouse_input(m: &wiard::event::MouseInput, dpi: f32) -> glane::MouseInput {
             wiard::ButtonState::Pressed => glane::ButtonState::Pressed,
             wiard::ButtonState::Released => glane::ButtonState::Released,
         },
+        click_count: 1,
         mouse_state: mouse_state(&m.mouse_state, dpi),
     }
 }
@@ -55,7 +57,7 @@ fn mouse_wheel(m: &wiard::event::MouseWheel, dpi: f32) -> glane::MouseWheel {
             wiard::MouseWheelAxis::Vertical => glane::MouseWheelAxis::Vertical,
             wiard::MouseWheelAxis::Horizontal => glane::MouseWheelAxis::Horizontal,
         },
-        distance: -m.distance / wiard::WHEEL_DELTA,
+        distance: -m.distance as f32 / wiard::WHEEL_DELTA as f32,
         mouse_state: mouse_state(&m.mouse_state, dpi),
     }
 }
@@ -132,9 +134,9 @@ impl Canvas {
                 }
                 if l.handle().type_id() == TypeId::of::<Button>() {
                     let brush = match area.widget_state {
-                        glane::WidgetState::None => &self.button_bg,
                         glane::WidgetState::Hover => &self.button_bg_hover,
                         glane::WidgetState::Pressed => &self.button_bg_pressed,
+                        _ => &self.button_bg,
                     };
                     let rect = l.rect();
                     cmd.fill(
@@ -310,7 +312,10 @@ fn main() -> anyhow::Result<()> {
                 scene.input(
                     glane::Input::KeyInput(glane::KeyInput {
                         vkey: ev.key_code.vkey,
+                        scan_code: ev.key_code.scan_code,
                         key_state: ev.key_state,
+                        modifiers: glane::Modifiers::NONE,
+                        repeat: false,
                     }),
                     &mut events,
                 );
@@ -1,9 +1,13 @@
 use super::*;
 
+const PADDING: LogicalRect<f32> = LogicalRect::new(7.0, 3.0, 7.0, 3.0);
+
 #[derive(Debug)]
 pub struct Style {
     pub font: Option<Font>,
-    pub padding: LogicalRect<f32>,
+    pub padding: Option<LogicalRect<f32>>,
+    pub icon_size: LogicalSize<f32>,
+    pub icon_spacing: f32,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -16,6 +20,7 @@ pub struct Button {
     id: Id,
     widget_state: WidgetState,
     pub text: String,
+    pub icon: Option<ImageSource>,
     pub style: Style,
 }
 
@@ -26,12 +31,29 @@ impl Button {
             id: Id::new(),
             widget_state: WidgetState::None,
             text: text.into(),
+            icon: None,
             style: Style {
                 font: None,
-                padding: LogicalRect::new(7.0, 3.0, 7.0, 3.0),
+                padding: None,
+                icon_size: LogicalSize::new(16.0, 16.0),
+                icon_spacing: 4.0,
             },
         }
     }
+
+    #[inline]
+    pub fn with_icon(mut self, icon: impl Into<ImageSource>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    fn icon_width(&self) -> f32 {
+        match (&self.icon, self.text.is_empty()) {
+            (None, _) => 0.0,
+            (Some(_), true) => self.style.icon_size.width,
+            (Some(_), false) => self.style.icon_size.width + self.style.icon_spacing,
+        }
+    }
 }
 
 impl HasId for Button {
@@ -42,6 +64,9 @@ impl HasId for Button {
 
 impl Widget for Button {
     fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        if ctx.is_disabled(self) {
+            return ControlFlow::Continue;
+        }
         let Some(layout) = ctx.find_layout(self).nth(0) else {
             return ControlFlow::Continue;
         };
@@ -70,6 +95,14 @@ impl Widget for Button {
                     self.widget_state = events.push_state_changed(self, state, self.widget_state);
                 }
             }
+            Input::KeyInput(k)
+                if ctx.has_focus(self)
+                    && k.key_state == KeyState::Pressed
+                    && !k.repeat
+                    && matches!(k.vkey, VirtualKey::Space | VirtualKey::Enter) =>
+            {
+                events.push_message(self, Message::Clicked);
+            }
             _ => {}
         }
         ControlFlow::Continue
@@ -80,15 +113,23 @@ impl Widget for Button {
     }
 
     fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
+        let style = ctx.ctx.style(self, "button");
         let font = self
             .style
             .font
             .as_ref()
+            .or(style.font.as_ref())
             .unwrap_or_else(|| ctx.ctx.default_font.as_ref().unwrap());
+        let padding = self.style.padding.or(style.padding).unwrap_or(PADDING);
         let rect = bounding_box_with_str(ctx.ctx, font, &self.text);
+        let height = if self.icon.is_some() {
+            rect.bottom.max(self.style.icon_size.height)
+        } else {
+            rect.bottom
+        };
         LogicalSize::new(
-            rect.right + self.style.padding.left + self.style.padding.right,
-            rect.bottom + self.style.padding.top + self.style.padding.bottom,
+            self.icon_width() + rect.right + padding.left + padding.right,
+            height + padding.top + padding.bottom,
         )
     }
 
@@ -96,42 +137,83 @@ impl Widget for Button {
         SizeTypes::fix()
     }
 
+    fn baseline(&self, lc: &LayoutContext) -> Option<f32> {
+        let style = lc.ctx.style(self, "button");
+        let font = self
+            .style
+            .font
+            .as_ref()
+            .or(style.font.as_ref())
+            .or(lc.ctx.default_font.as_ref())?;
+        let padding = self.style.padding.or(style.padding).unwrap_or(PADDING);
+        Some(padding.top + font.ascent())
+    }
+
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let state = lc.state(self, self.widget_state);
+        result.push_focusable(&lc, self);
+        let focused = lc.ctx.has_focus(self);
+        let style = lc
+            .ctx
+            .animate_style(self, &lc.ctx.style(self, "button"), state, focused);
+        let padding = self.style.padding.or(style.padding).unwrap_or(PADDING);
         let size = self.size(&lc);
         let rect = LogicalRect::from_position_size(lc.rect.left_top(), size);
         result.push(
             &lc,
-            LayoutElement::area(
-                self,
-                self.widget_state,
-                rect,
-                &lc.ancestors,
-                lc.layer,
-                false,
-            ),
+            LayoutElement::area(self, state, rect, &lc.ancestors, lc.layer, false)
+                .with_style(&style, state, focused),
         );
         let rect = LogicalRect::new(
-            rect.left + self.style.padding.left,
-            rect.top + self.style.padding.top,
-            rect.right - self.style.padding.right,
-            rect.bottom - self.style.padding.bottom,
+            rect.left + padding.left,
+            rect.top + padding.top,
+            rect.right - padding.right,
+            rect.bottom - padding.bottom,
+        );
+        if let Some(icon) = self.icon.as_ref() {
+            let icon_size = self.style.icon_size;
+            let top = rect.top + (rect.bottom - rect.top - icon_size.height) / 2.0;
+            result.push(
+                &lc,
+                LayoutElement::image(
+                    self,
+                    state,
+                    LogicalRect::from_position_size(
+                        LogicalPosition::new(rect.left, top),
+                        icon_size,
+                    ),
+                    &lc.ancestors,
+                    icon.clone(),
+                    ImageFit::Contain,
+                    lc.layer,
+                )
+                .with_style(&style, state, false),
+            );
+        }
+        let rect = LogicalRect::new(
+            rect.left + self.icon_width(),
+            rect.top,
+            rect.right,
+            rect.bottom,
         );
         result.push(
             &lc,
             LayoutElement::text(
                 self,
-                self.widget_state,
+                state,
                 rect,
                 &lc.ancestors,
                 self.style
                     .font
                     .as_ref()
+                    .or(style.font.as_ref())
                     .or(lc.ctx.default_font.as_ref())
                     .cloned(),
                 self.text.clone(),
                 lc.layer,
                 false,
-            ),
+            )
+            .with_style(&style, state, false),
         );
     }
 }
@@ -0,0 +1,149 @@
+use super::*;
+
+#[derive(Clone, Debug)]
+pub struct Shape {
+    pub path: Path,
+    pub fill: Option<Color>,
+    pub stroke: Option<Color>,
+    pub stroke_width: f32,
+}
+
+impl Shape {
+    #[inline]
+    pub fn new(path: Path) -> Self {
+        Self {
+            path,
+            fill: None,
+            stroke: None,
+            stroke_width: 0.0,
+        }
+    }
+
+    #[inline]
+    pub fn fill(mut self, color: impl Into<Color>) -> Self {
+        self.fill = Some(color.into());
+        self
+    }
+
+    #[inline]
+    pub fn stroke(mut self, color: impl Into<Color>, width: f32) -> Self {
+        self.stroke = Some(color.into());
+        self.stroke_width = width;
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct Canvas {
+    id: Id,
+    shapes: Vec<Shape>,
+    pub size: LogicalSize<f32>,
+    pub view_box: Option<LogicalRect<f32>>,
+}
+
+impl Canvas {
+    #[inline]
+    pub fn new(size: impl Into<LogicalSize<f32>>) -> Self {
+        Self {
+            id: Id::new(),
+            shapes: vec![],
+            size: size.into(),
+            view_box: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_view_box(mut self, view_box: impl Into<LogicalRect<f32>>) -> Self {
+        self.view_box = Some(view_box.into());
+        self
+    }
+
+    #[inline]
+    pub fn push(&mut self, shape: Shape) {
+        self.shapes.push(shape);
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.shapes.clear();
+    }
+
+    #[inline]
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    #[inline]
+    pub fn shapes_mut(&mut self) -> &mut Vec<Shape> {
+        &mut self.shapes
+    }
+
+    fn transform(&self, origin: LogicalPosition<f32>, path: &Path) -> Path {
+        let (offset, scale) = match self.view_box {
+            Some(view_box) => {
+                let size = view_box.size();
+                (
+                    LogicalPosition::new(view_box.left, view_box.top),
+                    LogicalSize::new(self.size.width / size.width, self.size.height / size.height),
+                )
+            }
+            None => (LogicalPosition::new(0.0, 0.0), LogicalSize::new(1.0, 1.0)),
+        };
+        path.clone()
+            .translate(-offset.x, -offset.y)
+            .scale(scale.width, scale.height)
+            .translate(origin.x, origin.y)
+    }
+}
+
+impl HasId for Canvas {
+    #[inline]
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for Canvas {
+    fn input(&mut self, _ctx: &Context, _input: &Input, _events: &mut Events) -> ControlFlow {
+        ControlFlow::Continue
+    }
+
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        funcs.apply(self);
+    }
+
+    fn size(&self, _lc: &LayoutContext) -> LogicalSize<f32> {
+        self.size
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        SizeTypes::fix()
+    }
+
+    fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let state = lc.state(self, WidgetState::None);
+        let rect = LogicalRect::from_position_size(lc.rect.left_top(), self.size);
+        result.push(
+            &lc,
+            LayoutElement::start_clipping(self, rect, &lc.ancestors, lc.layer),
+        );
+        for shape in &self.shapes {
+            result.push(
+                &lc,
+                LayoutElement::path(
+                    self,
+                    state,
+                    &lc.ancestors,
+                    self.transform(rect.left_top(), &shape.path),
+                    lc.layer,
+                )
+                .with_color(shape.fill)
+                .with_stroke(shape.stroke, shape.stroke_width),
+            );
+        }
+        result.push(
+            &lc,
+            LayoutElement::end_clipping(self, rect, &lc.ancestors, lc.layer),
+        );
+    }
+}
@@ -96,6 +96,9 @@ impl HasId for CheckBox {
 
 impl Widget for CheckBox {
     fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        if ctx.is_disabled(self) {
+            return ControlFlow::Continue;
+        }
         let Some(layout) = ctx.find_layout(self).nth(0) else {
             return ControlFlow::Continue;
         };
@@ -139,6 +142,15 @@ impl Widget for CheckBox {
                     self.widget_state = events.push_state_changed(self, state, self.widget_state);
                 }
             }
+            Input::KeyInput(k)
+                if ctx.has_focus(self)
+                    && k.key_state == KeyState::Pressed
+                    && !k.repeat
+                    && k.vkey == VirtualKey::Space =>
+            {
+                self.checked = !self.checked;
+                events.push_message(self, Message::Clicked(self.checked));
+            }
             _ => {}
         }
         ControlFlow::Continue
@@ -146,6 +158,7 @@ impl Widget for CheckBox {
 
     fn apply(&mut self, funcs: &mut ApplyFuncs) {
         funcs.apply(self);
+        self.check.apply(funcs);
     }
 
     fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
@@ -161,32 +174,46 @@ impl Widget for CheckBox {
         SizeTypes::new(SizeType::Flexible, SizeType::Fix)
     }
 
+    fn baseline(&self, lc: &LayoutContext) -> Option<f32> {
+        self.label.baseline(lc)
+    }
+
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
-        let text_size = self.label.size(&lc);
+        let state = lc.state(self, self.widget_state);
+        result.push_focusable(&lc, self);
+        let focused = lc.ctx.has_focus(self);
+        let text_size = lc.child_size(&self.label);
         let rect = LogicalRect::from_position_size(
             lc.rect.left_top(),
             (text_size.height, text_size.height),
         );
         result.push(
             &lc,
-            LayoutElement::area(
-                self,
-                self.widget_state,
-                rect,
-                &lc.ancestors,
-                lc.layer,
-                false,
+            LayoutElement::area(self, state, rect, &lc.ancestors, lc.layer, false).with_style(
+                &lc.ctx
+                    .animate_style(self, &lc.ctx.style(self, "check_box"), state, focused),
+                state,
+                focused,
             ),
         );
         if self.checked {
             result.push(
                 &lc,
-                LayoutElement::area(
+                LayoutElement::path(
                     &self.check,
-                    self.widget_state,
-                    rect,
-                    &lc.ancestors,
+                    state,
+                    &lc.next(self, rect, lc.layer, lc.selected).ancestors,
+                    Path::check_mark(&rect),
                     lc.layer,
+                )
+                .with_style(
+                    &lc.ctx.animate_style(
+                        &self.check,
+                        &lc.ctx.style(&self.check, "check_box.check"),
+                        state,
+                        false,
+                    ),
+                    state,
                     false,
                 ),
             );
@@ -197,8 +224,7 @@ impl Widget for CheckBox {
             rect.right + self.style.spacing + text_size.width,
             rect.bottom,
         );
-        self.label
-            .layout(lc.next(self, rect, lc.layer, false), result);
+        result.layout_child(&self.label, lc.next(self, rect, lc.layer, false));
     }
 }
 
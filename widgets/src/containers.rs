@@ -1,11 +1,27 @@
 pub mod abs;
+pub mod align;
 pub mod column;
+pub mod flex;
+pub mod grid;
 pub mod max_size;
+pub mod min_size;
+pub mod padding;
+#[cfg(test)]
+mod probe;
 pub mod row;
+pub mod sized_box;
+pub mod stack;
 
 pub use abs::Abs;
+pub use align::Align;
 pub use column::Column;
+pub use flex::{Flex, FlexItem};
+pub use grid::Grid;
 pub use max_size::MaxSize;
+pub use min_size::MinSize;
+pub use padding::Padding;
 pub use row::Row;
+pub use sized_box::SizedBox;
+pub use stack::{Stack, StackItem};
 
 use super::*;
@@ -53,14 +53,14 @@ where
     }
 
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
-        self.child.layout(
+        result.layout_child(
+            &self.child,
             lc.next(
                 self,
                 LogicalRect::from_positions(self.position, lc.rect.right_bottom()),
                 lc.layer,
                 lc.selected,
             ),
-            result,
         );
     }
 }
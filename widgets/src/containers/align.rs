@@ -0,0 +1,98 @@
+use super::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Alignment {
+    Start,
+    Center,
+    End,
+    Fraction(f32),
+}
+
+impl Alignment {
+    #[inline]
+    pub fn fraction(&self) -> f32 {
+        match *self {
+            Self::Start => 0.0,
+            Self::Center => 0.5,
+            Self::End => 1.0,
+            Self::Fraction(f) => f,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Align {
+    id: Id,
+    child: Box<dyn Widget>,
+    pub horizontal: Alignment,
+    pub vertical: Alignment,
+}
+
+impl Align {
+    #[inline]
+    pub fn new(horizontal: Alignment, vertical: Alignment, child: impl Widget) -> Self {
+        Self {
+            id: Id::new(),
+            child: Box::new(child),
+            horizontal,
+            vertical,
+        }
+    }
+
+    #[inline]
+    pub fn center(child: impl Widget) -> Self {
+        Self::new(Alignment::Center, Alignment::Center, child)
+    }
+
+    fn child_rect(&self, lc: &LayoutContext) -> LogicalRect<f32> {
+        let available = lc.rect.size();
+        let size = lc.measure_child(self.child.as_ref(), Constraints::loose(available));
+        LogicalRect::from_position_size(
+            (
+                lc.rect.left + (available.width - size.width) * self.horizontal.fraction(),
+                lc.rect.top + (available.height - size.height) * self.vertical.fraction(),
+            ),
+            size,
+        )
+    }
+}
+
+impl HasId for Align {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for Align {
+    fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        self.child.input(ctx, input, events)
+    }
+
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        funcs.apply(self);
+        self.child.apply(funcs);
+    }
+
+    fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
+        ctx.rect.size()
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        SizeTypes::flexible()
+    }
+
+    fn baseline(&self, lc: &LayoutContext) -> Option<f32> {
+        let rect = self.child_rect(lc);
+        self.child
+            .baseline(&lc.with_rect(rect))
+            .map(|baseline| rect.top - lc.rect.top + baseline)
+    }
+
+    fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let rect = self.child_rect(&lc);
+        result.layout_child(
+            self.child.as_ref(),
+            lc.next(self, rect, lc.layer, lc.selected),
+        );
+    }
+}
@@ -1,11 +1,16 @@
 use super::*;
+use flex::{AlignItems, Justify};
+use std::collections::HashMap;
 
 #[derive(Debug)]
 pub struct Column {
     id: Id,
     children: Vec<Box<dyn Widget>>,
+    align_self: HashMap<Id, AlignItems>,
     pub space: f32,
     pub max_height: Option<f32>,
+    pub align_items: AlignItems,
+    pub justify_content: Justify,
 }
 
 impl Column {
@@ -14,10 +19,113 @@ impl Column {
         Self {
             id: Id::new(),
             children: vec![],
+            align_self: HashMap::new(),
             space: 10.0,
             max_height: None,
+            align_items: AlignItems::Start,
+            justify_content: Justify::Start,
         }
     }
+
+    #[inline]
+    pub fn align_self(&self, child: &impl HasId) -> AlignItems {
+        self.child_align(child.id())
+    }
+
+    #[inline]
+    pub fn set_align_self(&mut self, child: &impl HasId, align: Option<AlignItems>) {
+        match align {
+            Some(align) => self.align_self.insert(child.id(), align),
+            None => self.align_self.remove(&child.id()),
+        };
+    }
+
+    fn child_align(&self, id: Id) -> AlignItems {
+        self.align_self
+            .get(&id)
+            .copied()
+            .unwrap_or(self.align_items)
+    }
+
+    fn arrange(&self, lc: &LayoutContext) -> (LogicalSize<f32>, Vec<Option<LogicalRect<f32>>>) {
+        let available = lc.rect.size();
+        let max_height = self.max_height.unwrap_or(f32::INFINITY);
+        let hints = self
+            .children
+            .iter()
+            .map(|child| lc.child_size_hint(child.as_ref()))
+            .collect::<Vec<_>>();
+        let flexible_count = hints.iter().filter(|h| h.is_flexible_height()).count();
+        let fixed = hints.iter().filter(|h| !h.is_flexible_height());
+        let fixed_height = fixed
+            .clone()
+            .map(|h| h.preferred.height.min(max_height))
+            .sum::<f32>();
+        let fixed_width = fixed.fold(0.0f32, |width, h| width.max(h.preferred.width));
+        let spaces = self.space * self.children.len().saturating_sub(1) as f32;
+        let flexible_height = if flexible_count > 0 {
+            ((available.height - fixed_height - spaces) / flexible_count as f32)
+                .clamp(0.0, max_height)
+        } else {
+            0.0
+        };
+        let mut size = LogicalSize::new(0.0f32, 0.0f32);
+        let sizes = self
+            .children
+            .iter()
+            .zip(hints.iter())
+            .map(|(child, hint)| {
+                let s = if hint.is_flexible_height() {
+                    lc.measure_child(
+                        child.as_ref(),
+                        Constraints::new(
+                            (fixed_width.min(available.width), flexible_height),
+                            (available.width, flexible_height),
+                        ),
+                    )
+                } else {
+                    let remaining = available.height - size.height;
+                    if remaining <= 0.0 {
+                        return None;
+                    }
+                    lc.measure_child(
+                        child.as_ref(),
+                        Constraints::loose((available.width, remaining.min(max_height))),
+                    )
+                };
+                size.width = size.width.max(s.width);
+                size.height += s.height + self.space;
+                Some(s)
+            })
+            .collect::<Vec<_>>();
+        let used = (size.height - self.space).max(0.0);
+        size.height = if flexible_count > 0 || self.justify_content != Justify::Start {
+            available.height
+        } else {
+            used.min(available.height)
+        };
+        let placed = sizes.iter().flatten().count();
+        let (offset, spacing) = if flexible_count > 0 {
+            (0.0, 0.0)
+        } else {
+            self.justify_content
+                .distribute(available.height - used, placed)
+        };
+        let mut y = offset;
+        let rects = self
+            .children
+            .iter()
+            .zip(sizes)
+            .map(|(child, s)| {
+                let s = s?;
+                let (x, width) = self.child_align(child.id()).place(size.width, s.width);
+                let rect = LogicalRect::from_position_size((x, y), (width, s.height));
+                y += s.height + self.space + spacing;
+                Some(rect)
+            })
+            .collect::<Vec<_>>();
+        (size, rects)
+    }
 }
 
 impl HasId for Column {
@@ -44,125 +152,56 @@ impl Widget for Column {
     }
 
     fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
-        let mut size = LogicalSize::new(0.0f32, 0.0);
-        for (i, child) in self.children.iter().enumerate() {
-            if child.size_types().height != SizeType::Fix {
-                continue;
-            }
-            let s = child.size(ctx);
-            size.width = s.width.max(size.width);
-            size.height += self.max_height.map_or(s.height, |mh| s.height.min(mh));
-            if i < self.children.len() - 1 {
-                size.height += self.space;
-            }
-        }
-        let flexible_count = self
-            .children
-            .iter()
-            .filter(|child| child.size_types().height == SizeType::Flexible)
-            .count();
-        if flexible_count > 0 {
-            size.height += ctx.rect.size().height - size.height;
-            let last = self.children.last().unwrap();
-            size.height += self.space
-                * (flexible_count
-                    - if last.size_types().width == SizeType::Flexible {
-                        1
-                    } else {
-                        0
-                    }) as f32;
-        }
-        size.height = size.height.min(ctx.rect.size().height);
-        size
+        self.arrange(ctx).0
+    }
+
+    fn baseline(&self, lc: &LayoutContext) -> Option<f32> {
+        let (_, rects) = self.arrange(lc);
+        self.children.iter().zip(rects).find_map(|(child, rect)| {
+            let rect = rect?;
+            let baseline = child.baseline(&lc.with_rect(LogicalRect::from_position_size(
+                lc.rect.left_top(),
+                rect.size(),
+            )))?;
+            Some(rect.top + baseline)
+        })
     }
 
     fn size_types(&self) -> SizeTypes {
-        self.children
-            .iter()
-            .fold(SizeTypes::fix(), |r, child| SizeTypes {
-                width: if child.size_types().width == SizeType::Flexible {
-                    SizeType::Flexible
-                } else {
-                    r.width
-                },
-                height: if child.size_types().height == SizeType::Flexible {
-                    SizeType::Flexible
-                } else {
-                    r.height
-                },
-            })
+        let init = SizeTypes::new(
+            SizeType::Fix,
+            if self.justify_content == Justify::Start {
+                SizeType::Fix
+            } else {
+                SizeType::Flexible
+            },
+        );
+        self.children.iter().fold(init, |r, child| SizeTypes {
+            width: if child.size_types().width == SizeType::Flexible {
+                SizeType::Flexible
+            } else {
+                r.width
+            },
+            height: if child.size_types().height == SizeType::Flexible {
+                SizeType::Flexible
+            } else {
+                r.height
+            },
+        })
     }
 
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
-        let size = self.size(&lc);
-        let mut rect = lc.rect;
-        let mut sizes = Vec::with_capacity(self.children.len());
-        let mut max_width = 0.0f32;
-        let mut h = 0.0;
-        for (i, child) in self.children.iter().enumerate() {
-            if child.size_types().height == SizeType::Flexible {
-                h += self.space;
-                sizes.push(None);
-            } else {
-                let s = child.size(&lc);
-                max_width = max_width.max(s.width);
-                let height = self.max_height.map_or(s.height, |mh| s.height.min(mh));
-                if h + height <= size.height {
-                    sizes.push(Some(LogicalSize::new(s.width, height)));
-                    h += height;
-                    if i < self.children.len() - 1 {
-                        h += self.space;
-                    }
-                } else {
-                    let height = size.height - h;
-                    if height > 0.0 {
-                        sizes.push(Some(LogicalSize::new(s.width, height)));
-                    } else {
-                        sizes.push(None);
-                    }
-                }
-            }
-        }
-        let flexible_count = self
-            .children
-            .iter()
-            .filter(|child| child.size_types().height == SizeType::Flexible)
-            .count();
-        let flexible_height = if flexible_count > 0 {
-            (size.height - h) / flexible_count as f32
-        } else {
-            0.0
-        };
-        let flexible_height = self
-            .max_height
-            .map_or(flexible_height, |mh| mh.min(flexible_height));
-        for (i, child) in self.children.iter().enumerate() {
-            let s = match child.size_types().height {
-                SizeType::Flexible => {
-                    let size = child.size(&lc);
-                    let width = max_width.max(size.width);
-                    LogicalSize::new(width, flexible_height)
-                }
-                _ => {
-                    if let Some(size) = sizes[i] {
-                        size
-                    } else {
-                        continue;
-                    }
-                }
+        let (_, rects) = self.arrange(&lc);
+        let origin = lc.rect.left_top();
+        for (child, rect) in self.children.iter().zip(rects) {
+            let Some(rect) = rect else {
+                continue;
             };
-            child.layout(
-                lc.next(
-                    self,
-                    LogicalRect::from_position_size(rect.left_top(), s),
-                    lc.layer,
-                    lc.selected,
-                ),
-                result,
+            let rect = LogicalRect::from_position_size(
+                (origin.x + rect.left, origin.y + rect.top),
+                rect.size(),
             );
-            if i < self.children.len() - 1 {
-                rect.top += s.height + self.space;
-            }
+            result.layout_child(child.as_ref(), lc.next(self, rect, lc.layer, lc.selected));
         }
     }
 }
@@ -188,6 +227,7 @@ impl HasChildren for Column {
             return;
         };
         self.children.remove(index);
+        self.align_self.remove(&object.id());
     }
 }
 
@@ -0,0 +1,633 @@
+use super::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum Direction {
+    #[default]
+    Row,
+    Column,
+}
+
+impl Direction {
+    fn main(&self, size: LogicalSize<f32>) -> f32 {
+        match self {
+            Self::Row => size.width,
+            Self::Column => size.height,
+        }
+    }
+
+    fn cross(&self, size: LogicalSize<f32>) -> f32 {
+        match self {
+            Self::Row => size.height,
+            Self::Column => size.width,
+        }
+    }
+
+    fn size(&self, main: f32, cross: f32) -> LogicalSize<f32> {
+        match self {
+            Self::Row => LogicalSize::new(main, cross),
+            Self::Column => LogicalSize::new(cross, main),
+        }
+    }
+
+    fn position(&self, main: f32, cross: f32) -> LogicalPosition<f32> {
+        match self {
+            Self::Row => LogicalPosition::new(main, cross),
+            Self::Column => LogicalPosition::new(cross, main),
+        }
+    }
+
+    fn main_type(&self, types: SizeTypes) -> SizeType {
+        match self {
+            Self::Row => types.width,
+            Self::Column => types.height,
+        }
+    }
+
+    fn cross_type(&self, types: SizeTypes) -> SizeType {
+        match self {
+            Self::Row => types.height,
+            Self::Column => types.width,
+        }
+    }
+
+    fn main_limit(&self, limit: LogicalSize<Option<f32>>) -> Option<f32> {
+        match self {
+            Self::Row => limit.width,
+            Self::Column => limit.height,
+        }
+    }
+
+    fn cross_limit(&self, limit: LogicalSize<Option<f32>>) -> Option<f32> {
+        match self {
+            Self::Row => limit.height,
+            Self::Column => limit.width,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum Wrap {
+    #[default]
+    NoWrap,
+    Wrap,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum Justify {
+    #[default]
+    Start,
+    End,
+    Center,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[non_exhaustive]
+pub enum AlignItems {
+    #[default]
+    Start,
+    End,
+    Center,
+    Stretch,
+    /// Aligns text baselines in rows; behaves as `Start` in columns.
+    Baseline,
+}
+
+impl Justify {
+    pub(crate) fn distribute(&self, remaining: f32, count: usize) -> (f32, f32) {
+        let remaining = remaining.max(0.0);
+        match self {
+            Self::Start => (0.0, 0.0),
+            Self::End => (remaining, 0.0),
+            Self::Center => (remaining / 2.0, 0.0),
+            Self::SpaceBetween if count > 1 => (0.0, remaining / (count - 1) as f32),
+            Self::SpaceBetween => (0.0, 0.0),
+            Self::SpaceAround if count > 0 => {
+                let s = remaining / count as f32;
+                (s / 2.0, s)
+            }
+            Self::SpaceAround => (0.0, 0.0),
+            Self::SpaceEvenly => {
+                let s = remaining / (count + 1) as f32;
+                (s, s)
+            }
+        }
+    }
+}
+
+impl AlignItems {
+    pub(crate) fn place(&self, line: f32, size: f32) -> (f32, f32) {
+        let size = size.min(line);
+        match self {
+            Self::Start | Self::Baseline => (0.0, size),
+            Self::End => (line - size, size),
+            Self::Center => ((line - size) / 2.0, size),
+            Self::Stretch => (0.0, line),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FlexItem {
+    /// `None` lets flexible children without a basis grow by 1 and keeps fixed ones at 0.
+    pub grow: Option<f32>,
+    pub shrink: f32,
+    pub basis: Option<f32>,
+    pub min: LogicalSize<Option<f32>>,
+    pub max: LogicalSize<Option<f32>>,
+}
+
+impl FlexItem {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            grow: None,
+            shrink: 1.0,
+            basis: None,
+            min: LogicalSize::new(None, None),
+            max: LogicalSize::new(None, None),
+        }
+    }
+
+    #[inline]
+    pub fn grow(mut self, grow: f32) -> Self {
+        self.grow = Some(grow);
+        self
+    }
+
+    #[inline]
+    pub fn shrink(mut self, shrink: f32) -> Self {
+        self.shrink = shrink;
+        self
+    }
+
+    #[inline]
+    pub fn basis(mut self, basis: f32) -> Self {
+        self.basis = Some(basis);
+        self
+    }
+
+    #[inline]
+    pub fn min(mut self, width: Option<f32>, height: Option<f32>) -> Self {
+        self.min = LogicalSize::new(width, height);
+        self
+    }
+
+    #[inline]
+    pub fn max(mut self, width: Option<f32>, height: Option<f32>) -> Self {
+        self.max = LogicalSize::new(width, height);
+        self
+    }
+}
+
+impl Default for FlexItem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug)]
+struct Child {
+    widget: Box<dyn Widget>,
+    item: FlexItem,
+}
+
+impl Child {
+    fn grow(&self, dir: Direction) -> f32 {
+        let flexible = dir.main_type(self.widget.size_types()) == SizeType::Flexible;
+        self.item
+            .grow
+            .unwrap_or(if flexible && self.item.basis.is_none() {
+                1.0
+            } else {
+                0.0
+            })
+    }
+}
+
+#[derive(Debug)]
+pub struct Flex {
+    id: Id,
+    children: Vec<Child>,
+    pub direction: Direction,
+    pub wrap: Wrap,
+    pub justify_content: Justify,
+    pub align_items: AlignItems,
+    pub gap: f32,
+    pub cross_gap: f32,
+}
+
+impl Flex {
+    #[inline]
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            id: Id::new(),
+            children: vec![],
+            direction,
+            wrap: Wrap::NoWrap,
+            justify_content: Justify::Start,
+            align_items: AlignItems::Start,
+            gap: 10.0,
+            cross_gap: 10.0,
+        }
+    }
+
+    #[inline]
+    pub fn row() -> Self {
+        Self::new(Direction::Row)
+    }
+
+    #[inline]
+    pub fn column() -> Self {
+        Self::new(Direction::Column)
+    }
+
+    #[inline]
+    pub fn push_with(&mut self, child: impl Widget, item: FlexItem) {
+        self.children.push(Child {
+            widget: Box::new(child),
+            item,
+        });
+    }
+
+    #[inline]
+    pub fn item(&self, child: &impl HasId) -> Option<&FlexItem> {
+        let id = child.id();
+        self.children
+            .iter()
+            .find(|c| c.widget.id() == id)
+            .map(|c| &c.item)
+    }
+
+    #[inline]
+    pub fn set_item(&mut self, child: &impl HasId, item: FlexItem) {
+        let id = child.id();
+        if let Some(c) = self.children.iter_mut().find(|c| c.widget.id() == id) {
+            c.item = item;
+        }
+    }
+
+    fn clamp(value: f32, min: Option<f32>, max: Option<f32>) -> f32 {
+        let value = max.map_or(value, |max| value.min(max));
+        min.map_or(value, |min| value.max(min))
+    }
+
+    fn arrange(&self, lc: &LayoutContext) -> (LogicalSize<f32>, Vec<LogicalRect<f32>>) {
+        let dir = self.direction;
+        let available = lc.rect.size();
+        let main_available = dir.main(available);
+        let cross_available = dir.cross(available);
+        let mut mains = Vec::with_capacity(self.children.len());
+        let mut crosses = Vec::with_capacity(self.children.len());
+        let mut grows = Vec::with_capacity(self.children.len());
+        for child in &self.children {
+            let item = &child.item;
+            let types = child.widget.size_types();
+            let constraints = Constraints::loose((
+                item.max.width.unwrap_or(available.width),
+                item.max.height.unwrap_or(available.height),
+            ));
+            let size = lc.measure_child(child.widget.as_ref(), constraints);
+            // Flexible children without a basis start empty and take a share of the free space,
+            // like they do in `Row` and `Column`.
+            let flexible = dir.main_type(types) == SizeType::Flexible;
+            let main = item
+                .basis
+                .unwrap_or(if flexible { 0.0 } else { dir.main(size) });
+            mains.push(Self::clamp(
+                main,
+                dir.main_limit(item.min),
+                dir.main_limit(item.max),
+            ));
+            crosses.push(Self::clamp(
+                dir.cross(size),
+                dir.cross_limit(item.min),
+                dir.cross_limit(item.max),
+            ));
+            grows.push(child.grow(dir));
+        }
+        let mut lines = vec![];
+        let mut start = 0;
+        let mut used = 0.0;
+        for (i, main) in mains.iter().enumerate() {
+            let gap = if i > start { self.gap } else { 0.0 };
+            if self.wrap == Wrap::Wrap && i > start && used + gap + main > main_available {
+                lines.push(start..i);
+                start = i;
+                used = *main;
+            } else {
+                used += gap + main;
+            }
+        }
+        if start < self.children.len() {
+            lines.push(start..self.children.len());
+        }
+        let fill_cross = self.wrap == Wrap::NoWrap
+            && self
+                .children
+                .iter()
+                .any(|c| dir.cross_type(c.widget.size_types()) == SizeType::Flexible);
+        let mut rects = vec![LogicalRect::new(0.0, 0.0, 0.0, 0.0); self.children.len()];
+        let mut main_used = 0.0f32;
+        let mut cross_pos = 0.0;
+        let mut grown = false;
+        for range in lines {
+            let count = range.len();
+            let gaps = self.gap * (count - 1) as f32;
+            let sum = mains[range.clone()].iter().sum::<f32>();
+            let free = main_available - sum - gaps;
+            let mut sizes = mains[range.clone()].to_vec();
+            if free > 0.0 {
+                let total = grows[range.clone()].iter().sum::<f32>();
+                if total > 0.0 {
+                    grown = true;
+                    for (k, i) in range.clone().enumerate() {
+                        let item = &self.children[i].item;
+                        sizes[k] = Self::clamp(
+                            sizes[k] + free * grows[i] / total,
+                            dir.main_limit(item.min),
+                            dir.main_limit(item.max),
+                        );
+                    }
+                }
+            } else if free < 0.0 {
+                let total = range
+                    .clone()
+                    .map(|i| self.children[i].item.shrink * mains[i])
+                    .sum::<f32>();
+                if total > 0.0 {
+                    for (k, i) in range.clone().enumerate() {
+                        let item = &self.children[i].item;
+                        let shrink = item.shrink * mains[i] / total;
+                        sizes[k] = Self::clamp(
+                            (sizes[k] + free * shrink).max(0.0),
+                            dir.main_limit(item.min),
+                            dir.main_limit(item.max),
+                        );
+                    }
+                }
+            }
+            let baselines = range
+                .clone()
+                .enumerate()
+                .map(|(k, i)| {
+                    if self.align_items != AlignItems::Baseline || dir != Direction::Row {
+                        return None;
+                    }
+                    self.children[i].widget.baseline(&lc.with_rect(
+                        LogicalRect::from_position_size(
+                            lc.rect.left_top(),
+                            dir.size(sizes[k], crosses[i]),
+                        ),
+                    ))
+                })
+                .collect::<Vec<_>>();
+            let ascent = baselines.iter().flatten().fold(0.0f32, |a, b| a.max(*b));
+            let line_cross = if fill_cross {
+                cross_available
+            } else {
+                range
+                    .clone()
+                    .zip(baselines.iter())
+                    .map(|(i, baseline)| baseline.map_or(0.0, |b| ascent - b) + crosses[i])
+                    .fold(0.0f32, |a, b| a.max(b))
+            };
+            let remaining = main_available - sizes.iter().sum::<f32>() - gaps;
+            let (offset, spacing) = self.justify_content.distribute(remaining, count);
+            let mut main_pos = offset;
+            for (k, i) in range.enumerate() {
+                let item = &self.children[i].item;
+                let cross = match self.align_items {
+                    AlignItems::Stretch => Self::clamp(
+                        line_cross,
+                        dir.cross_limit(item.min),
+                        dir.cross_limit(item.max),
+                    ),
+                    _ => crosses[i].min(line_cross),
+                };
+                let cross_offset = match self.align_items {
+                    AlignItems::Baseline => baselines[k].map_or(0.0, |b| ascent - b),
+                    AlignItems::Start | AlignItems::Stretch => 0.0,
+                    AlignItems::End => line_cross - cross,
+                    AlignItems::Center => (line_cross - cross) / 2.0,
+                };
+                rects[i] = LogicalRect::from_position_size(
+                    dir.position(main_pos, cross_pos + cross_offset),
+                    dir.size(sizes[k], cross),
+                );
+                main_pos += sizes[k] + self.gap + spacing;
+            }
+            main_used = main_used.max(main_pos - self.gap - spacing);
+            cross_pos += line_cross + self.cross_gap;
+        }
+        let fills_main = self.wrap == Wrap::Wrap || grown || self.justify_content != Justify::Start;
+        let main = if fills_main {
+            main_available
+        } else {
+            main_used.min(main_available)
+        };
+        let cross = (cross_pos - self.cross_gap).max(0.0);
+        (dir.size(main, cross), rects)
+    }
+}
+
+impl HasId for Flex {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for Flex {
+    fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        for child in self.children.iter_mut() {
+            if child.widget.input(ctx, input, events) == ControlFlow::Break {
+                return ControlFlow::Break;
+            }
+        }
+        ControlFlow::Continue
+    }
+
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        funcs.apply(self);
+        for child in self.children.iter_mut() {
+            child.widget.apply(funcs);
+        }
+    }
+
+    fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
+        self.arrange(ctx).0
+    }
+
+    fn baseline(&self, lc: &LayoutContext) -> Option<f32> {
+        let (_, rects) = self.arrange(lc);
+        self.children.iter().zip(rects).find_map(|(child, rect)| {
+            let baseline =
+                child
+                    .widget
+                    .baseline(&lc.with_rect(LogicalRect::from_position_size(
+                        lc.rect.left_top(),
+                        rect.size(),
+                    )))?;
+            Some(rect.top + baseline)
+        })
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        let dir = self.direction;
+        let main = self.wrap == Wrap::Wrap
+            || self.justify_content != Justify::Start
+            || self.children.iter().any(|c| {
+                c.grow(dir) > 0.0 || dir.main_type(c.widget.size_types()) == SizeType::Flexible
+            });
+        let cross = self
+            .children
+            .iter()
+            .any(|c| dir.cross_type(c.widget.size_types()) == SizeType::Flexible);
+        let size_type = |flexible: bool| {
+            if flexible {
+                SizeType::Flexible
+            } else {
+                SizeType::Fix
+            }
+        };
+        match dir {
+            Direction::Row => SizeTypes::new(size_type(main), size_type(cross)),
+            Direction::Column => SizeTypes::new(size_type(cross), size_type(main)),
+        }
+    }
+
+    fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let (_, rects) = self.arrange(&lc);
+        let origin = lc.rect.left_top();
+        for (child, rect) in self.children.iter().zip(rects) {
+            let rect = LogicalRect::new(
+                origin.x + rect.left,
+                origin.y + rect.top,
+                origin.x + rect.right,
+                origin.y + rect.bottom,
+            );
+            result.layout_child(
+                child.widget.as_ref(),
+                lc.next(self, rect, lc.layer, lc.selected),
+            );
+        }
+    }
+}
+
+impl HasChildren for Flex {
+    #[inline]
+    fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    #[inline]
+    fn push(&mut self, child: impl Widget) {
+        self.push_with(child, FlexItem::default());
+    }
+
+    #[inline]
+    fn erase(&mut self, object: &impl HasId) {
+        let Some(index) = self
+            .children
+            .iter()
+            .position(|child| child.widget.id() == object.id())
+        else {
+            return;
+        };
+        self.children.remove(index);
+    }
+}
+
+impl Default for Flex {
+    fn default() -> Self {
+        Self::row()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::containers::probe::Probe;
+
+    fn flexible() -> Probe {
+        Probe::new(0.0, 20.0).flexible(SizeTypes::new(SizeType::Flexible, SizeType::Fix))
+    }
+
+    fn layout(flex: Flex, width: f32) {
+        let (mut scene, _) = Scene::new(flex);
+        scene.set_viewport(LogicalSize::new(width, 100.0));
+        scene.layout();
+    }
+
+    fn grow(widget: Probe, item: FlexItem, dir: Direction) -> f32 {
+        Child {
+            widget: Box::new(widget),
+            item,
+        }
+        .grow(dir)
+    }
+
+    #[test]
+    fn default_grow() {
+        assert_eq!(grow(flexible(), FlexItem::new(), Direction::Row), 1.0);
+        assert_eq!(grow(flexible(), FlexItem::new(), Direction::Column), 0.0);
+        assert_eq!(
+            grow(flexible(), FlexItem::new().basis(10.0), Direction::Row),
+            0.0
+        );
+        assert_eq!(
+            grow(Probe::new(10.0, 10.0), FlexItem::new(), Direction::Row),
+            0.0
+        );
+        let mut flex = Flex::row();
+        let probe = flexible();
+        let rect = probe.rect();
+        flex.push_with(probe, FlexItem::new());
+        flex.push_with(Probe::new(100.0, 20.0), FlexItem::new());
+        layout(flex, 500.0);
+        assert_eq!(rect.get().unwrap().size().width, 390.0);
+    }
+
+    #[test]
+    fn zero_grow() {
+        assert_eq!(
+            grow(flexible(), FlexItem::new().grow(0.0), Direction::Row),
+            0.0
+        );
+        let mut flex = Flex::row();
+        let probe = flexible();
+        let rect = probe.rect();
+        flex.push_with(probe, FlexItem::new().grow(0.0));
+        flex.push_with(Probe::new(100.0, 20.0), FlexItem::new());
+        layout(flex, 500.0);
+        assert_eq!(rect.get().unwrap().size().width, 0.0);
+    }
+
+    #[test]
+    fn grow_shares_free_space() {
+        let mut flex = Flex::row();
+        let a = Probe::new(100.0, 20.0);
+        let b = Probe::new(100.0, 20.0);
+        let (a_rect, b_rect) = (a.rect(), b.rect());
+        flex.push_with(a, FlexItem::new().grow(1.0));
+        flex.push_with(b, FlexItem::new().grow(3.0));
+        layout(flex, 510.0);
+        assert_eq!(a_rect.get().unwrap().size().width, 175.0);
+        assert_eq!(b_rect.get().unwrap().size().width, 325.0);
+        assert_eq!(b_rect.get().unwrap().left, 185.0);
+    }
+
+    #[test]
+    fn justify_distribute() {
+        assert_eq!(Justify::Start.distribute(90.0, 3), (0.0, 0.0));
+        assert_eq!(Justify::End.distribute(90.0, 3), (90.0, 0.0));
+        assert_eq!(Justify::Center.distribute(90.0, 3), (45.0, 0.0));
+        assert_eq!(Justify::SpaceBetween.distribute(90.0, 3), (0.0, 45.0));
+        assert_eq!(Justify::SpaceBetween.distribute(90.0, 1), (0.0, 0.0));
+        assert_eq!(Justify::SpaceAround.distribute(90.0, 3), (15.0, 30.0));
+        assert_eq!(Justify::SpaceEvenly.distribute(90.0, 2), (30.0, 30.0));
+        assert_eq!(Justify::SpaceEvenly.distribute(-10.0, 2), (0.0, 0.0));
+    }
+}
@@ -0,0 +1,443 @@
+use super::*;
+use std::ops::Range;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Track {
+    Fixed(f32),
+    Auto,
+    Fraction(f32),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum Align {
+    #[default]
+    Start,
+    End,
+    Center,
+    Stretch,
+}
+
+impl Align {
+    fn place(&self, start: f32, cell: f32, size: f32) -> (f32, f32) {
+        let size = size.min(cell);
+        match self {
+            Self::Start => (start, size),
+            Self::End => (start + cell - size, size),
+            Self::Center => (start + (cell - size) / 2.0, size),
+            Self::Stretch => (start, cell),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Placement {
+    pub row: usize,
+    pub column: usize,
+    pub row_span: usize,
+    pub column_span: usize,
+    pub horizontal: Align,
+    pub vertical: Align,
+}
+
+impl Placement {
+    #[inline]
+    pub fn new(row: usize, column: usize) -> Self {
+        Self {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+            horizontal: Align::Start,
+            vertical: Align::Start,
+        }
+    }
+
+    #[inline]
+    pub fn span(mut self, rows: usize, columns: usize) -> Self {
+        self.row_span = rows.max(1);
+        self.column_span = columns.max(1);
+        self
+    }
+
+    #[inline]
+    pub fn align(mut self, horizontal: Align, vertical: Align) -> Self {
+        self.horizontal = horizontal;
+        self.vertical = vertical;
+        self
+    }
+
+    fn rows(&self) -> Range<usize> {
+        self.row..self.row + self.row_span.max(1)
+    }
+
+    fn columns(&self) -> Range<usize> {
+        self.column..self.column + self.column_span.max(1)
+    }
+
+    fn contains(&self, row: usize, column: usize) -> bool {
+        self.rows().contains(&row) && self.columns().contains(&column)
+    }
+}
+
+#[derive(Debug)]
+struct Cell {
+    widget: Box<dyn Widget>,
+    placement: Placement,
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    id: Id,
+    children: Vec<Cell>,
+    pub columns: Vec<Track>,
+    pub rows: Vec<Track>,
+    pub column_gap: f32,
+    pub row_gap: f32,
+}
+
+impl Grid {
+    #[inline]
+    pub fn new(columns: impl Into<Vec<Track>>, rows: impl Into<Vec<Track>>) -> Self {
+        Self {
+            id: Id::new(),
+            children: vec![],
+            columns: columns.into(),
+            rows: rows.into(),
+            column_gap: 10.0,
+            row_gap: 10.0,
+        }
+    }
+
+    #[inline]
+    pub fn place(&mut self, child: impl Widget, placement: Placement) {
+        self.children.push(Cell {
+            widget: Box::new(child),
+            placement,
+        });
+    }
+
+    #[inline]
+    pub fn placement(&self, child: &impl HasId) -> Option<&Placement> {
+        let id = child.id();
+        self.children
+            .iter()
+            .find(|c| c.widget.id() == id)
+            .map(|c| &c.placement)
+    }
+
+    #[inline]
+    pub fn set_placement(&mut self, child: &impl HasId, placement: Placement) {
+        let id = child.id();
+        if let Some(c) = self.children.iter_mut().find(|c| c.widget.id() == id) {
+            c.placement = placement;
+        }
+    }
+
+    fn next_free(&self) -> Placement {
+        let columns = self.columns.len().max(1);
+        (0..)
+            .map(|i| Placement::new(i / columns, i % columns))
+            .find(|p| {
+                !self
+                    .children
+                    .iter()
+                    .any(|c| c.placement.contains(p.row, p.column))
+            })
+            .unwrap()
+    }
+
+    fn tracks(
+        &self,
+        defined: &[Track],
+        available: f32,
+        gap: f32,
+        cell: impl Fn(&Placement) -> Range<usize>,
+        extent: impl Fn(&Cell) -> f32,
+    ) -> Vec<f32> {
+        let count = self
+            .children
+            .iter()
+            .map(|c| cell(&c.placement).end)
+            .max()
+            .unwrap_or(0)
+            .max(defined.len());
+        let track = |i: usize| defined.get(i).copied().unwrap_or(Track::Auto);
+        let mut sizes = (0..count)
+            .map(|i| match track(i) {
+                Track::Fixed(size) => size,
+                _ => 0.0,
+            })
+            .collect::<Vec<_>>();
+        let mut children = self
+            .children
+            .iter()
+            .map(|c| (cell(&c.placement), c))
+            .collect::<Vec<_>>();
+        children.sort_by_key(|(range, _)| range.len());
+        for (range, c) in children {
+            let autos = range
+                .clone()
+                .filter(|i| track(*i) == Track::Auto)
+                .collect::<Vec<_>>();
+            if autos.is_empty() {
+                continue;
+            }
+            let size = extent(c);
+            let current = Self::total(&sizes[range], gap);
+            if size > current {
+                let extra = (size - current) / autos.len() as f32;
+                for i in autos {
+                    sizes[i] += extra;
+                }
+            }
+        }
+        let fractions = (0..count)
+            .filter_map(|i| match track(i) {
+                Track::Fraction(f) => Some(f),
+                _ => None,
+            })
+            .sum::<f32>();
+        if fractions > 0.0 {
+            let used = sizes.iter().sum::<f32>() + gap * count.saturating_sub(1) as f32;
+            let free = (available - used).max(0.0);
+            for (i, size) in sizes.iter_mut().enumerate() {
+                if let Track::Fraction(f) = track(i) {
+                    *size = free * f / fractions;
+                }
+            }
+        }
+        sizes
+    }
+
+    fn arrange(&self, lc: &LayoutContext) -> (Vec<f32>, Vec<f32>) {
+        let available = lc.rect.size();
+        let columns = self.tracks(
+            &self.columns,
+            available.width,
+            self.column_gap,
+            Placement::columns,
+            |c| match c.widget.size_types().width {
+                SizeType::Flexible => 0.0,
+                SizeType::Fix => {
+                    let constraints = Constraints::loose(available);
+                    lc.measure_child(c.widget.as_ref(), constraints).width
+                }
+            },
+        );
+        let rows = self.tracks(
+            &self.rows,
+            available.height,
+            self.row_gap,
+            Placement::rows,
+            |c| match c.widget.size_types().height {
+                SizeType::Flexible => 0.0,
+                SizeType::Fix => {
+                    let width = Self::total(&columns[c.placement.columns()], self.column_gap);
+                    let constraints = Constraints::loose((width, available.height));
+                    lc.measure_child(c.widget.as_ref(), constraints).height
+                }
+            },
+        );
+        (columns, rows)
+    }
+
+    fn total(tracks: &[f32], gap: f32) -> f32 {
+        tracks.iter().sum::<f32>() + gap * tracks.len().saturating_sub(1) as f32
+    }
+
+    fn offset(tracks: &[f32], gap: f32, index: usize) -> f32 {
+        tracks[..index].iter().map(|t| t + gap).sum()
+    }
+
+    fn child_rects(&self, lc: &LayoutContext) -> Vec<LogicalRect<f32>> {
+        let (columns, rows) = self.arrange(lc);
+        let origin = lc.rect.left_top();
+        self.children
+            .iter()
+            .map(|child| {
+                let p = &child.placement;
+                let left = origin.x + Self::offset(&columns, self.column_gap, p.column);
+                let top = origin.y + Self::offset(&rows, self.row_gap, p.row);
+                let width = Self::total(&columns[p.columns()], self.column_gap);
+                let height = Self::total(&rows[p.rows()], self.row_gap);
+                let size =
+                    lc.measure_child(child.widget.as_ref(), Constraints::loose((width, height)));
+                let (left, width) = p.horizontal.place(left, width, size.width);
+                let (top, height) = p.vertical.place(top, height, size.height);
+                LogicalRect::from_position_size((left, top), (width, height))
+            })
+            .collect()
+    }
+}
+
+impl HasId for Grid {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for Grid {
+    fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        for child in self.children.iter_mut() {
+            if child.widget.input(ctx, input, events) == ControlFlow::Break {
+                return ControlFlow::Break;
+            }
+        }
+        ControlFlow::Continue
+    }
+
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        funcs.apply(self);
+        for child in self.children.iter_mut() {
+            child.widget.apply(funcs);
+        }
+    }
+
+    fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
+        let (columns, rows) = self.arrange(ctx);
+        LogicalSize::new(
+            Self::total(&columns, self.column_gap),
+            Self::total(&rows, self.row_gap),
+        )
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        let size_type = |tracks: &[Track]| {
+            if tracks.iter().any(|t| matches!(t, Track::Fraction(_))) {
+                SizeType::Flexible
+            } else {
+                SizeType::Fix
+            }
+        };
+        SizeTypes::new(size_type(&self.columns), size_type(&self.rows))
+    }
+
+    fn baseline(&self, lc: &LayoutContext) -> Option<f32> {
+        self.children
+            .iter()
+            .zip(self.child_rects(lc))
+            .find_map(|(child, rect)| {
+                let baseline = child.widget.baseline(&lc.with_rect(rect))?;
+                Some(rect.top - lc.rect.top + baseline)
+            })
+    }
+
+    fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        for (child, rect) in self.children.iter().zip(self.child_rects(&lc)) {
+            result.layout_child(
+                child.widget.as_ref(),
+                lc.next(self, rect, lc.layer, lc.selected),
+            );
+        }
+    }
+}
+
+impl HasChildren for Grid {
+    #[inline]
+    fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    #[inline]
+    fn push(&mut self, child: impl Widget) {
+        let placement = self.next_free();
+        self.place(child, placement);
+    }
+
+    #[inline]
+    fn erase(&mut self, object: &impl HasId) {
+        let Some(index) = self
+            .children
+            .iter()
+            .position(|child| child.widget.id() == object.id())
+        else {
+            return;
+        };
+        self.children.remove(index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::containers::probe::Probe;
+
+    fn grid(placements: &[Placement]) -> Grid {
+        let mut grid = Grid::new(vec![], vec![]);
+        for placement in placements {
+            grid.place(Probe::new(0.0, 0.0), *placement);
+        }
+        grid
+    }
+
+    fn zero_span(row: usize, column: usize) -> Placement {
+        Placement {
+            row_span: 0,
+            column_span: 0,
+            ..Placement::new(row, column)
+        }
+    }
+
+    #[test]
+    fn span_is_at_least_one() {
+        let p = Placement::new(1, 2).span(0, 0);
+        assert_eq!((p.row_span, p.column_span), (1, 1));
+        let p = zero_span(1, 2);
+        assert_eq!(p.rows(), 1..2);
+        assert_eq!(p.columns(), 2..3);
+        assert!(p.contains(1, 2));
+    }
+
+    #[test]
+    fn zero_span_tracks() {
+        let grid = grid(&[zero_span(0, 1)]);
+        let tracks = grid.tracks(&[], 100.0, 10.0, Placement::columns, |_| 30.0);
+        assert_eq!(tracks, vec![0.0, 30.0]);
+    }
+
+    #[test]
+    fn spanning_child_grows_auto_tracks() {
+        let grid = grid(&[Placement::new(0, 0).span(1, 3)]);
+        let tracks = grid.tracks(
+            &[Track::Fixed(20.0), Track::Auto, Track::Auto],
+            200.0,
+            10.0,
+            Placement::columns,
+            |_| 80.0,
+        );
+        assert_eq!(tracks, vec![20.0, 20.0, 20.0]);
+    }
+
+    #[test]
+    fn fractions_share_free_space() {
+        let grid = grid(&[]);
+        let tracks = grid.tracks(
+            &[
+                Track::Fixed(20.0),
+                Track::Fraction(1.0),
+                Track::Fraction(3.0),
+            ],
+            120.0,
+            10.0,
+            Placement::columns,
+            |_| 0.0,
+        );
+        assert_eq!(tracks, vec![20.0, 20.0, 60.0]);
+    }
+
+    #[test]
+    fn zero_span_layout() {
+        let probe = Probe::new(10.0, 10.0);
+        let rect = probe.rect();
+        let mut grid = Grid::new(
+            vec![Track::Fixed(100.0), Track::Fixed(50.0)],
+            vec![Track::Fixed(40.0)],
+        );
+        grid.place(probe, zero_span(0, 1).align(Align::Stretch, Align::Stretch));
+        let (mut scene, _) = Scene::new(grid);
+        scene.layout();
+        let rect = rect.get().unwrap();
+        assert_eq!(rect.left, 110.0);
+        assert_eq!(rect.size(), LogicalSize::new(50.0, 40.0));
+    }
+}
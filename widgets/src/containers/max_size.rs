@@ -46,16 +46,40 @@ impl Widget for MaxSize {
         SizeTypes::fix()
     }
 
+    fn baseline(&self, lc: &LayoutContext) -> Option<f32> {
+        let rect = LogicalRect::from_position_size(lc.rect.left_top(), self.size(lc));
+        self.child.baseline(&lc.with_rect(rect))
+    }
+
+    fn measure(&self, lc: &LayoutContext, constraints: Constraints) -> LogicalSize<f32> {
+        let max = LogicalSize::new(
+            self.size
+                .width
+                .map_or(constraints.max.width, |m| constraints.max.width.min(m)),
+            self.size
+                .height
+                .map_or(constraints.max.height, |m| constraints.max.height.min(m)),
+        );
+        let constraints = Constraints::new(
+            LogicalSize::new(
+                constraints.min.width.min(max.width),
+                constraints.min.height.min(max.height),
+            ),
+            max,
+        );
+        lc.measure_child(self.child.as_ref(), constraints)
+    }
+
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
         let size = self.size(&lc);
-        self.child.layout(
+        result.layout_child(
+            self.child.as_ref(),
             lc.next(
                 self,
                 LogicalRect::from_position_size(lc.rect.left_top(), size),
                 lc.layer,
                 lc.selected,
             ),
-            result,
         );
     }
 }
@@ -0,0 +1,85 @@
+use super::*;
+
+#[derive(Debug)]
+pub struct MinSize {
+    id: Id,
+    child: Box<dyn Widget>,
+    pub size: LogicalSize<Option<f32>>,
+}
+
+impl MinSize {
+    #[inline]
+    pub fn new(width: Option<f32>, height: Option<f32>, child: impl Widget) -> Self {
+        Self {
+            id: Id::new(),
+            child: Box::new(child),
+            size: LogicalSize::new(width, height),
+        }
+    }
+}
+
+impl HasId for MinSize {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for MinSize {
+    fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        self.child.input(ctx, input, events)
+    }
+
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        funcs.apply(self);
+        self.child.apply(funcs);
+    }
+
+    fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
+        let size = self.child.size(ctx);
+        LogicalSize::new(
+            self.size.width.map_or(size.width, |m| size.width.max(m)),
+            self.size.height.map_or(size.height, |m| size.height.max(m)),
+        )
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        self.child.size_types()
+    }
+
+    fn baseline(&self, lc: &LayoutContext) -> Option<f32> {
+        let rect = LogicalRect::from_position_size(lc.rect.left_top(), self.size(lc));
+        self.child.baseline(&lc.with_rect(rect))
+    }
+
+    fn measure(&self, lc: &LayoutContext, constraints: Constraints) -> LogicalSize<f32> {
+        let min = LogicalSize::new(
+            self.size
+                .width
+                .map_or(constraints.min.width, |m| constraints.min.width.max(m)),
+            self.size
+                .height
+                .map_or(constraints.min.height, |m| constraints.min.height.max(m)),
+        );
+        let constraints = Constraints::new(
+            min,
+            LogicalSize::new(
+                constraints.max.width.max(min.width),
+                constraints.max.height.max(min.height),
+            ),
+        );
+        lc.measure_child(self.child.as_ref(), constraints)
+    }
+
+    fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let size = self.size(&lc);
+        result.layout_child(
+            self.child.as_ref(),
+            lc.next(
+                self,
+                LogicalRect::from_position_size(lc.rect.left_top(), size),
+                lc.layer,
+                lc.selected,
+            ),
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use super::*;
+
+#[derive(Debug)]
+pub struct Padding {
+    id: Id,
+    child: Box<dyn Widget>,
+    pub padding: LogicalRect<f32>,
+}
+
+impl Padding {
+    #[inline]
+    pub fn new(padding: impl Into<LogicalRect<f32>>, child: impl Widget) -> Self {
+        Self {
+            id: Id::new(),
+            child: Box::new(child),
+            padding: padding.into(),
+        }
+    }
+
+    #[inline]
+    pub fn all(padding: f32, child: impl Widget) -> Self {
+        Self::new((padding, padding, padding, padding), child)
+    }
+
+    fn horizontal(&self) -> f32 {
+        self.padding.left + self.padding.right
+    }
+
+    fn vertical(&self) -> f32 {
+        self.padding.top + self.padding.bottom
+    }
+
+    fn inner(&self, rect: &LogicalRect<f32>) -> LogicalRect<f32> {
+        LogicalRect::new(
+            rect.left + self.padding.left,
+            rect.top + self.padding.top,
+            (rect.right - self.padding.right).max(rect.left + self.padding.left),
+            (rect.bottom - self.padding.bottom).max(rect.top + self.padding.top),
+        )
+    }
+}
+
+impl HasId for Padding {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for Padding {
+    fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        self.child.input(ctx, input, events)
+    }
+
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        funcs.apply(self);
+        self.child.apply(funcs);
+    }
+
+    fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
+        let size = ctx
+            .with_rect(self.inner(&ctx.rect))
+            .child_size(self.child.as_ref());
+        LogicalSize::new(
+            size.width + self.horizontal(),
+            size.height + self.vertical(),
+        )
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        self.child.size_types()
+    }
+
+    fn baseline(&self, lc: &LayoutContext) -> Option<f32> {
+        self.child
+            .baseline(&lc.with_rect(self.inner(&lc.rect)))
+            .map(|baseline| baseline + self.padding.top)
+    }
+
+    fn measure(&self, lc: &LayoutContext, constraints: Constraints) -> LogicalSize<f32> {
+        let lc = lc.with_rect(self.inner(&lc.rect));
+        let size = lc.measure_child(
+            self.child.as_ref(),
+            constraints.shrink(self.horizontal(), self.vertical()),
+        );
+        constraints.constrain(LogicalSize::new(
+            size.width + self.horizontal(),
+            size.height + self.vertical(),
+        ))
+    }
+
+    fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let rect = self.inner(&lc.rect);
+        result.layout_child(
+            self.child.as_ref(),
+            lc.next(self, rect, lc.layer, lc.selected),
+        );
+    }
+}
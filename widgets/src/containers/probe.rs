@@ -0,0 +1,59 @@
+use super::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub(crate) struct Probe {
+    id: Id,
+    size: LogicalSize<f32>,
+    size_types: SizeTypes,
+    rect: Rc<Cell<Option<LogicalRect<f32>>>>,
+}
+
+impl Probe {
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            id: Id::new(),
+            size: LogicalSize::new(width, height),
+            size_types: SizeTypes::fix(),
+            rect: Rc::new(Cell::new(None)),
+        }
+    }
+
+    pub fn flexible(mut self, size_types: SizeTypes) -> Self {
+        self.size_types = size_types;
+        self
+    }
+
+    pub fn rect(&self) -> Rc<Cell<Option<LogicalRect<f32>>>> {
+        self.rect.clone()
+    }
+}
+
+impl HasId for Probe {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for Probe {
+    fn input(&mut self, _ctx: &Context, _input: &Input, _events: &mut Events) -> ControlFlow {
+        ControlFlow::Continue
+    }
+
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        funcs.apply(self);
+    }
+
+    fn size(&self, _ctx: &LayoutContext) -> LogicalSize<f32> {
+        self.size
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        self.size_types
+    }
+
+    fn layout(&self, lc: LayoutContext, _result: &mut LayoutConstructor) {
+        self.rect.set(Some(lc.rect));
+    }
+}
@@ -1,10 +1,15 @@
 use super::*;
+use flex::{AlignItems, Justify};
+use std::collections::HashMap;
 
 #[derive(Debug)]
 pub struct Row {
     id: Id,
     children: Vec<Box<dyn Widget>>,
+    align_self: HashMap<Id, AlignItems>,
     pub space: f32,
+    pub align_items: AlignItems,
+    pub justify_content: Justify,
 }
 
 impl Row {
@@ -13,9 +18,131 @@ impl Row {
         Self {
             id: Id::new(),
             children: vec![],
+            align_self: HashMap::new(),
             space: 10.0,
+            align_items: AlignItems::Start,
+            justify_content: Justify::Start,
         }
     }
+
+    #[inline]
+    pub fn align_self(&self, child: &impl HasId) -> AlignItems {
+        self.child_align(child.id())
+    }
+
+    #[inline]
+    pub fn set_align_self(&mut self, child: &impl HasId, align: Option<AlignItems>) {
+        match align {
+            Some(align) => self.align_self.insert(child.id(), align),
+            None => self.align_self.remove(&child.id()),
+        };
+    }
+
+    fn child_align(&self, id: Id) -> AlignItems {
+        self.align_self
+            .get(&id)
+            .copied()
+            .unwrap_or(self.align_items)
+    }
+
+    fn arrange(&self, lc: &LayoutContext) -> (LogicalSize<f32>, Vec<Option<LogicalRect<f32>>>) {
+        let available = lc.rect.size();
+        let hints = self
+            .children
+            .iter()
+            .map(|child| lc.child_size_hint(child.as_ref()))
+            .collect::<Vec<_>>();
+        let flexible_count = hints.iter().filter(|h| h.is_flexible_width()).count();
+        let fixed = hints.iter().filter(|h| !h.is_flexible_width());
+        let fixed_width = fixed.clone().map(|h| h.preferred.width).sum::<f32>();
+        let fixed_height = fixed.fold(0.0f32, |height, h| height.max(h.preferred.height));
+        let spaces = self.space * self.children.len().saturating_sub(1) as f32;
+        let flexible_width = if flexible_count > 0 {
+            ((available.width - fixed_width - spaces) / flexible_count as f32).max(0.0)
+        } else {
+            0.0
+        };
+        let mut size = LogicalSize::new(0.0f32, 0.0f32);
+        let sizes = self
+            .children
+            .iter()
+            .zip(hints.iter())
+            .map(|(child, hint)| {
+                let s = if hint.is_flexible_width() {
+                    lc.measure_child(
+                        child.as_ref(),
+                        Constraints::new(
+                            (flexible_width, fixed_height.min(available.height)),
+                            (flexible_width, available.height),
+                        ),
+                    )
+                } else {
+                    let remaining = available.width - size.width;
+                    if remaining <= 0.0 {
+                        return None;
+                    }
+                    lc.measure_child(
+                        child.as_ref(),
+                        Constraints::loose((remaining, available.height)),
+                    )
+                };
+                size.width += s.width + self.space;
+                size.height = size.height.max(s.height);
+                Some(s)
+            })
+            .collect::<Vec<_>>();
+        let used = (size.width - self.space).max(0.0);
+        size.width = if flexible_count > 0 || self.justify_content != Justify::Start {
+            available.width
+        } else {
+            used.min(available.width)
+        };
+        let placed = sizes.iter().flatten().count();
+        let (offset, spacing) = if flexible_count > 0 {
+            (0.0, 0.0)
+        } else {
+            self.justify_content
+                .distribute(available.width - used, placed)
+        };
+        let baselines = self
+            .children
+            .iter()
+            .zip(sizes.iter())
+            .map(|(child, s)| {
+                let s = (*s)?;
+                if self.child_align(child.id()) != AlignItems::Baseline {
+                    return None;
+                }
+                child
+                    .baseline(&lc.with_rect(LogicalRect::from_position_size(lc.rect.left_top(), s)))
+            })
+            .collect::<Vec<_>>();
+        let ascent = baselines.iter().flatten().fold(0.0f32, |a, b| a.max(*b));
+        for (s, baseline) in sizes.iter().zip(baselines.iter()) {
+            if let (Some(s), Some(baseline)) = (s, baseline) {
+                size.height = size.height.max(ascent - baseline + s.height);
+            }
+        }
+        size.height = size.height.min(available.height);
+        let mut x = offset;
+        let rects = self
+            .children
+            .iter()
+            .zip(sizes)
+            .zip(baselines)
+            .map(|((child, s), baseline)| {
+                let s = s?;
+                let (y, height) = match baseline {
+                    Some(baseline) => (ascent - baseline, s.height),
+                    None => self.child_align(child.id()).place(size.height, s.height),
+                };
+                let rect = LogicalRect::from_position_size((x, y), (s.width, height));
+                x += s.width + self.space + spacing;
+                Some(rect)
+            })
+            .collect::<Vec<_>>();
+        (size, rects)
+    }
 }
 
 impl HasId for Row {
@@ -42,122 +169,56 @@ impl Widget for Row {
     }
 
     fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
-        let mut size = LogicalSize::new(0.0, 0.0);
-        for (i, child) in self.children.iter().enumerate() {
-            if child.size_types().width != SizeType::Fix {
-                continue;
-            }
-            let s = child.size(ctx);
-            size.width += s.width;
-            if i < self.children.len() - 1 {
-                size.width += self.space;
-            }
-            size.height = s.height.max(size.height);
-        }
-        let flexible_count = self
-            .children
-            .iter()
-            .filter(|child| child.size_types().width == SizeType::Flexible)
-            .count();
-        if flexible_count > 0 {
-            size.width += ctx.rect.size().width - size.width;
-            let last = self.children.last().unwrap();
-            size.width += self.space
-                * (flexible_count
-                    - if last.size_types().width == SizeType::Flexible {
-                        1
-                    } else {
-                        0
-                    }) as f32;
-        }
-        size.width = size.width.min(ctx.rect.size().width);
-        size
+        self.arrange(ctx).0
+    }
+
+    fn baseline(&self, lc: &LayoutContext) -> Option<f32> {
+        let (_, rects) = self.arrange(lc);
+        self.children.iter().zip(rects).find_map(|(child, rect)| {
+            let rect = rect?;
+            let baseline = child.baseline(&lc.with_rect(LogicalRect::from_position_size(
+                lc.rect.left_top(),
+                rect.size(),
+            )))?;
+            Some(rect.top + baseline)
+        })
     }
 
     fn size_types(&self) -> SizeTypes {
-        self.children
-            .iter()
-            .fold(SizeTypes::fix(), |r, child| SizeTypes {
-                width: if child.size_types().width == SizeType::Flexible {
-                    SizeType::Flexible
-                } else {
-                    r.width
-                },
-                height: if child.size_types().height == SizeType::Flexible {
-                    SizeType::Flexible
-                } else {
-                    r.height
-                },
-            })
+        let init = SizeTypes::new(
+            if self.justify_content == Justify::Start {
+                SizeType::Fix
+            } else {
+                SizeType::Flexible
+            },
+            SizeType::Fix,
+        );
+        self.children.iter().fold(init, |r, child| SizeTypes {
+            width: if child.size_types().width == SizeType::Flexible {
+                SizeType::Flexible
+            } else {
+                r.width
+            },
+            height: if child.size_types().height == SizeType::Flexible {
+                SizeType::Flexible
+            } else {
+                r.height
+            },
+        })
     }
 
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
-        let size = self.size(&lc);
-        let mut rect = lc.rect;
-        let mut sizes = Vec::with_capacity(self.children.len());
-        let mut max_height = 0.0f32;
-        let mut w = 0.0;
-        for (i, child) in self.children.iter().enumerate() {
-            if child.size_types().width == SizeType::Flexible {
-                w += self.space;
-                sizes.push(None);
-            } else {
-                let s = child.size(&lc);
-                max_height = max_height.max(s.height);
-                let width = s.width;
-                if w + width <= size.width {
-                    sizes.push(Some(LogicalSize::new(width, s.height)));
-                    w += width;
-                    if i < self.children.len() - 1 {
-                        w += self.space;
-                    }
-                } else {
-                    let width = size.width - w;
-                    if width > 0.0 {
-                        sizes.push(Some(LogicalSize::new(width, s.height)));
-                    } else {
-                        sizes.push(None);
-                    }
-                };
-            }
-        }
-        let flexible_count = self
-            .children
-            .iter()
-            .filter(|child| child.size_types().width == SizeType::Flexible)
-            .count();
-        let flexible_width = if flexible_count > 0 {
-            (size.width - w) / flexible_count as f32
-        } else {
-            0.0
-        };
-        for (i, child) in self.children.iter().enumerate() {
-            let s = match child.size_types().width {
-                SizeType::Flexible => {
-                    let size = child.size(&lc);
-                    let height = max_height.max(size.height);
-                    LogicalSize::new(flexible_width, height)
-                }
-                _ => {
-                    if let Some(size) = sizes[i] {
-                        size
-                    } else {
-                        continue;
-                    }
-                }
+        let (_, rects) = self.arrange(&lc);
+        let origin = lc.rect.left_top();
+        for (child, rect) in self.children.iter().zip(rects) {
+            let Some(rect) = rect else {
+                continue;
             };
-            child.layout(
-                lc.next(
-                    self,
-                    LogicalRect::from_position_size(rect.left_top(), s),
-                    lc.layer,
-                    lc.selected,
-                ),
-                result,
+            let rect = LogicalRect::from_position_size(
+                (origin.x + rect.left, origin.y + rect.top),
+                rect.size(),
             );
-            if i < self.children.len() - 1 {
-                rect.left += s.width + self.space;
-            }
+            result.layout_child(child.as_ref(), lc.next(self, rect, lc.layer, lc.selected));
         }
     }
 }
@@ -183,6 +244,7 @@ impl HasChildren for Row {
             return;
         };
         self.children.remove(index);
+        self.align_self.remove(&object.id());
     }
 }
 
@@ -0,0 +1,118 @@
+use super::*;
+
+#[derive(Debug)]
+pub struct SizedBox {
+    id: Id,
+    child: Option<Box<dyn Widget>>,
+    pub size: LogicalSize<Option<f32>>,
+}
+
+impl SizedBox {
+    #[inline]
+    pub fn new(width: Option<f32>, height: Option<f32>, child: impl Widget) -> Self {
+        Self {
+            id: Id::new(),
+            child: Some(Box::new(child)),
+            size: LogicalSize::new(width, height),
+        }
+    }
+
+    #[inline]
+    pub fn spacer(width: f32, height: f32) -> Self {
+        Self {
+            id: Id::new(),
+            child: None,
+            size: LogicalSize::new(Some(width), Some(height)),
+        }
+    }
+
+    fn resolve(&self, size: LogicalSize<f32>) -> LogicalSize<f32> {
+        LogicalSize::new(
+            self.size.width.unwrap_or(size.width),
+            self.size.height.unwrap_or(size.height),
+        )
+    }
+}
+
+impl HasId for SizedBox {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for SizedBox {
+    fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        match self.child.as_mut() {
+            Some(child) => child.input(ctx, input, events),
+            None => ControlFlow::Continue,
+        }
+    }
+
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        funcs.apply(self);
+        if let Some(child) = self.child.as_mut() {
+            child.apply(funcs);
+        }
+    }
+
+    fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
+        let rect =
+            LogicalRect::from_position_size(ctx.rect.left_top(), self.resolve(ctx.rect.size()));
+        let size = self
+            .child
+            .as_ref()
+            .map_or(LogicalSize::new(0.0, 0.0), |child| {
+                ctx.with_rect(rect).child_size(child.as_ref())
+            });
+        self.resolve(size)
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        let size_types = self
+            .child
+            .as_ref()
+            .map_or(SizeTypes::fix(), |child| child.size_types());
+        SizeTypes::new(
+            if self.size.width.is_some() {
+                SizeType::Fix
+            } else {
+                size_types.width
+            },
+            if self.size.height.is_some() {
+                SizeType::Fix
+            } else {
+                size_types.height
+            },
+        )
+    }
+
+    fn baseline(&self, lc: &LayoutContext) -> Option<f32> {
+        let rect = LogicalRect::from_position_size(lc.rect.left_top(), self.size(lc));
+        self.child.as_ref()?.baseline(&lc.with_rect(rect))
+    }
+
+    fn measure(&self, lc: &LayoutContext, constraints: Constraints) -> LogicalSize<f32> {
+        let tight = Constraints::new(self.resolve(constraints.min), self.resolve(constraints.max));
+        let size = match self.child.as_ref() {
+            Some(child) => lc.measure_child(child.as_ref(), tight),
+            None => tight.constrain(LogicalSize::new(0.0, 0.0)),
+        };
+        constraints.constrain(size)
+    }
+
+    fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let Some(child) = self.child.as_ref() else {
+            return;
+        };
+        let size = self.size(&lc);
+        result.layout_child(
+            child.as_ref(),
+            lc.next(
+                self,
+                LogicalRect::from_position_size(lc.rect.left_top(), size),
+                lc.layer,
+                lc.selected,
+            ),
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use super::*;
+use align::Alignment;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct StackItem {
+    pub horizontal: Alignment,
+    pub vertical: Alignment,
+    pub offset: LogicalPosition<f32>,
+}
+
+impl StackItem {
+    #[inline]
+    pub fn new(horizontal: Alignment, vertical: Alignment) -> Self {
+        Self {
+            horizontal,
+            vertical,
+            offset: LogicalPosition::new(0.0, 0.0),
+        }
+    }
+
+    #[inline]
+    pub fn offset(mut self, x: f32, y: f32) -> Self {
+        self.offset = LogicalPosition::new(x, y);
+        self
+    }
+}
+
+impl Default for StackItem {
+    fn default() -> Self {
+        Self::new(Alignment::Start, Alignment::Start)
+    }
+}
+
+#[derive(Debug)]
+struct Child {
+    widget: Box<dyn Widget>,
+    item: StackItem,
+}
+
+#[derive(Debug)]
+pub struct Stack {
+    id: Id,
+    children: Vec<Child>,
+}
+
+impl Stack {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            children: vec![],
+        }
+    }
+
+    #[inline]
+    pub fn push_with(&mut self, child: impl Widget, item: StackItem) {
+        self.children.push(Child {
+            widget: Box::new(child),
+            item,
+        });
+    }
+
+    #[inline]
+    pub fn item(&self, child: &impl HasId) -> Option<&StackItem> {
+        let id = child.id();
+        self.children
+            .iter()
+            .find(|c| c.widget.id() == id)
+            .map(|c| &c.item)
+    }
+
+    #[inline]
+    pub fn set_item(&mut self, child: &impl HasId, item: StackItem) {
+        let id = child.id();
+        if let Some(c) = self.children.iter_mut().find(|c| c.widget.id() == id) {
+            c.item = item;
+        }
+    }
+
+    fn measure_children(&self, lc: &LayoutContext) -> Vec<LogicalSize<f32>> {
+        let constraints = Constraints::loose(lc.rect.size());
+        self.children
+            .iter()
+            .map(|child| lc.measure_child(child.widget.as_ref(), constraints))
+            .collect()
+    }
+
+    fn child_rects(&self, lc: &LayoutContext) -> Vec<LogicalRect<f32>> {
+        let sizes = self.measure_children(lc);
+        let area = sizes
+            .iter()
+            .fold(LogicalSize::new(0.0f32, 0.0f32), |size, s| {
+                LogicalSize::new(size.width.max(s.width), size.height.max(s.height))
+            });
+        self.children
+            .iter()
+            .zip(sizes)
+            .map(|(child, size)| {
+                let item = &child.item;
+                let position = LogicalPosition::new(
+                    lc.rect.left
+                        + (area.width - size.width) * item.horizontal.fraction()
+                        + item.offset.x,
+                    lc.rect.top
+                        + (area.height - size.height) * item.vertical.fraction()
+                        + item.offset.y,
+                );
+                LogicalRect::from_position_size(position, size)
+            })
+            .collect()
+    }
+}
+
+impl HasId for Stack {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for Stack {
+    fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        for child in self.children.iter_mut().rev() {
+            if child.widget.input(ctx, input, events) == ControlFlow::Break {
+                return ControlFlow::Break;
+            }
+        }
+        ControlFlow::Continue
+    }
+
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        funcs.apply(self);
+        for child in self.children.iter_mut() {
+            child.widget.apply(funcs);
+        }
+    }
+
+    fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
+        self.measure_children(ctx)
+            .into_iter()
+            .fold(LogicalSize::new(0.0, 0.0), |size, s| {
+                LogicalSize::new(size.width.max(s.width), size.height.max(s.height))
+            })
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        self.children.iter().fold(SizeTypes::fix(), |r, child| {
+            let size_types = child.widget.size_types();
+            SizeTypes {
+                width: if size_types.width == SizeType::Flexible {
+                    SizeType::Flexible
+                } else {
+                    r.width
+                },
+                height: if size_types.height == SizeType::Flexible {
+                    SizeType::Flexible
+                } else {
+                    r.height
+                },
+            }
+        })
+    }
+
+    fn baseline(&self, lc: &LayoutContext) -> Option<f32> {
+        self.children
+            .iter()
+            .zip(self.child_rects(lc))
+            .find_map(|(child, rect)| {
+                let baseline = child.widget.baseline(&lc.with_rect(rect))?;
+                Some(rect.top - lc.rect.top + baseline)
+            })
+    }
+
+    fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        for (i, (child, rect)) in self.children.iter().zip(self.child_rects(&lc)).enumerate() {
+            result.layout_child(
+                child.widget.as_ref(),
+                lc.next(self, rect, lc.layer + i as u32, lc.selected),
+            );
+        }
+    }
+}
+
+impl HasChildren for Stack {
+    #[inline]
+    fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    #[inline]
+    fn push(&mut self, child: impl Widget) {
+        self.push_with(child, StackItem::default());
+    }
+
+    #[inline]
+    fn erase(&mut self, object: &impl HasId) {
+        let Some(index) = self
+            .children
+            .iter()
+            .position(|child| child.widget.id() == object.id())
+        else {
+            return;
+        };
+        self.children.remove(index);
+    }
+}
+
+impl Default for Stack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
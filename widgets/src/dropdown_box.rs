@@ -45,6 +45,9 @@ impl HasId for DropdownBox {
 
 impl Widget for DropdownBox {
     fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        if ctx.is_disabled(self) {
+            return ControlFlow::Continue;
+        }
         let list_visiblity = self.list_visiblity;
         if let Some(layout) = ctx.find_layout(self).next() {
             let rect = layout.rect();
@@ -85,6 +88,15 @@ impl Widget for DropdownBox {
                         }
                     }
                 }
+                Input::KeyInput(k)
+                    if ctx.has_focus(self)
+                        && k.key_state == KeyState::Pressed
+                        && !k.repeat
+                        && matches!(k.vkey, VirtualKey::Space | VirtualKey::Enter) =>
+                {
+                    self.list_visiblity = !self.list_visiblity;
+                    ctx.invalidate(self);
+                }
                 _ => {}
             }
         }
@@ -102,10 +114,7 @@ impl Widget for DropdownBox {
                     .iter()
                     .enumerate()
                     .find_map(|(i, event)| match event.message(&self.list) {
-                        Some(msg) => {
-                            let list_box::Message::Selected(selected) = msg;
-                            Some((i, *selected))
-                        }
+                        Some(list_box::Message::Selected(selected)) => Some((i, *selected)),
                         _ => None,
                     });
             if let Some((i, selected)) = ret {
@@ -122,6 +131,7 @@ impl Widget for DropdownBox {
 
     fn apply(&mut self, funcs: &mut ApplyFuncs) {
         funcs.apply(self);
+        self.list.apply(funcs);
     }
 
     fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
@@ -147,25 +157,39 @@ impl Widget for DropdownBox {
     }
 
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let state = lc.state(self, self.widget_state);
+        result.push_focusable(&lc, self);
+        let focused = lc.ctx.has_focus(self);
         let size = self.size(&lc);
+        let style = lc
+            .ctx
+            .animate_style(self, &lc.ctx.style(self, "dropdown_box"), state, focused);
         let mut rect = LogicalRect::from_position_size(lc.rect.left_top(), size);
         result.push(
             &lc,
-            LayoutElement::area(
-                self,
-                self.widget_state,
-                rect,
-                &lc.ancestors,
-                lc.layer,
-                false,
-            ),
+            LayoutElement::area(self, state, rect, &lc.ancestors, lc.layer, false)
+                .with_style(&style, state, focused),
         );
         rect.left += self.padding.left;
         rect.top += self.padding.top;
         rect.right -= self.padding.right;
         rect.bottom -= self.padding.bottom;
+        let chevron_size = rect.bottom - rect.top;
+        let chevron_rect =
+            LogicalRect::new(rect.right - chevron_size, rect.top, rect.right, rect.bottom);
+        let chevron = if self.list_visiblity {
+            Path::chevron_up(&chevron_rect)
+        } else {
+            Path::chevron_down(&chevron_rect)
+        };
+        result.push(
+            &lc,
+            LayoutElement::path(self, state, &lc.ancestors, chevron, lc.layer)
+                .with_stroke(style.foreground(state, false), 1.5),
+        );
+        rect.right -= chevron_size;
         if let Some(child) = self.list.selected_child() {
-            child.layout(lc.next(self, rect, lc.layer, lc.selected), result);
+            result.layout_child(child, lc.next(self, rect, lc.layer, lc.selected));
         }
         if self.list_visiblity {
             let size = LogicalSize::new(
@@ -173,8 +197,7 @@ impl Widget for DropdownBox {
                 self.list_size.height.unwrap_or(100.0),
             );
             let rect = LogicalRect::from_position_size(lc.rect.left_bottom(), size);
-            self.list
-                .layout(lc.next(self, rect, lc.layer + 1, lc.selected), result);
+            result.layout_child(&self.list, lc.next(self, rect, lc.layer + 1, lc.selected));
         }
     }
 }
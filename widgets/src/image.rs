@@ -0,0 +1,77 @@
+use super::*;
+
+#[derive(Debug)]
+pub struct Image {
+    id: Id,
+    pub source: ImageSource,
+    pub fit: ImageFit,
+    pub size: Option<LogicalSize<f32>>,
+}
+
+impl Image {
+    #[inline]
+    pub fn new(source: impl Into<ImageSource>) -> Self {
+        Self {
+            id: Id::new(),
+            source: source.into(),
+            fit: ImageFit::default(),
+            size: None,
+        }
+    }
+
+    #[inline]
+    pub fn fit(mut self, fit: ImageFit) -> Self {
+        self.fit = fit;
+        self
+    }
+
+    #[inline]
+    pub fn with_size(mut self, size: impl Into<LogicalSize<f32>>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+}
+
+impl HasId for Image {
+    #[inline]
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for Image {
+    fn input(&mut self, _ctx: &Context, _input: &Input, _events: &mut Events) -> ControlFlow {
+        ControlFlow::Continue
+    }
+
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        funcs.apply(self);
+    }
+
+    fn size(&self, _lc: &LayoutContext) -> LogicalSize<f32> {
+        self.size.unwrap_or_else(|| self.source.size())
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        SizeTypes::fix()
+    }
+
+    fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let state = lc.state(self, WidgetState::None);
+        let style = lc.ctx.style(self, "image");
+        let size = self.size(&lc);
+        result.push(
+            &lc,
+            LayoutElement::image(
+                self,
+                state,
+                LogicalRect::from_position_size(lc.rect.left_top(), size),
+                &lc.ancestors,
+                self.source.clone(),
+                self.fit,
+                lc.layer,
+            )
+            .with_style(&style, state, false),
+        );
+    }
+}
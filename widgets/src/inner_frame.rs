@@ -3,12 +3,13 @@ use std::cell::RefCell;
 
 pub struct InnerFrame {
     id: Id,
-    position: LogicalPosition<f32>,
     size: LogicalSize<f32>,
     vscroll: RefCell<VScrollBar>,
     hscroll: RefCell<HScrollBar>,
     children: Vec<Box<dyn Widget>>,
     entered: bool,
+    scroll_transition: Transition,
+    kinetic: Kinetic,
 }
 
 impl InnerFrame {
@@ -17,19 +18,34 @@ impl InnerFrame {
         let size = size.into();
         Self {
             id: Id::new(),
-            position: LogicalPosition::new(0.0, 0.0),
             size,
-            vscroll: RefCell::new(VScrollBar::new(size.height.ceil() as usize, 1)),
-            hscroll: RefCell::new(HScrollBar::new(size.width.ceil() as usize, 1)),
+            vscroll: RefCell::new(VScrollBar::new(size.height, 1.0)),
+            hscroll: RefCell::new(HScrollBar::new(size.width, 1.0)),
             children: vec![],
             entered: false,
+            scroll_transition: Transition::default(),
+            kinetic: Kinetic::default(),
         }
     }
 
+    #[inline]
+    pub fn set_scroll_transition(&mut self, transition: Transition) {
+        self.scroll_transition = transition;
+    }
+
     #[inline]
     pub fn virtual_size(&self) -> LogicalSize<f32> {
         self.size
     }
+
+    fn scroll(&mut self, dx: f32, dy: f32) -> bool {
+        let mut vbar = self.vscroll.borrow_mut();
+        let mut hbar = self.hscroll.borrow_mut();
+        let prev = (hbar.current(), vbar.current());
+        vbar.advance(dy);
+        hbar.advance(dx);
+        (hbar.current(), vbar.current()) != prev
+    }
 }
 
 impl HasId for InnerFrame {
@@ -40,6 +56,9 @@ impl HasId for InnerFrame {
 
 impl Widget for InnerFrame {
     fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        if ctx.is_disabled(self) {
+            return ControlFlow::Continue;
+        }
         let area = ctx.find_layout(self).find_map(|l| l.as_area());
         let left = if let Some(area) = area {
             let rc = area.rect;
@@ -47,6 +66,7 @@ impl Widget for InnerFrame {
                 Input::MouseInput(ev) => {
                     if rc.contains(&ev.mouse_state.position) {
                         self.entered = true;
+                        self.kinetic.stop();
                         None
                     } else if self.entered {
                         self.entered = false;
@@ -70,15 +90,13 @@ impl Widget for InnerFrame {
                         None
                     }
                 }
-                Input::MouseWheel(ev) => {
-                    if self.entered || rc.contains(&ev.mouse_state.position) {
-                        let mut vbar = self.vscroll.borrow_mut();
-                        let a = ctx.default_font.as_ref().map(|df| df.size).unwrap_or(1.0) as isize;
-                        let d = a * ev.distance as isize;
-                        vbar.advance(d);
-                        self.position.y = (self.position.y + d as f32)
-                            .max(0.0)
-                            .min(self.size.height - vbar.thumb.len as isize as f32);
+                Input::Tick => {
+                    if let Some((dx, dy)) = self.kinetic.step(ctx, self.id) {
+                        if self.scroll(dx, dy) {
+                            ctx.invalidate(self);
+                        } else {
+                            self.kinetic.stop();
+                        }
                     }
                     None
                 }
@@ -116,33 +134,36 @@ impl Widget for InnerFrame {
         if self.hscroll.borrow_mut().input(ctx, input, events) == ControlFlow::Break {
             return ControlFlow::Break;
         }
-        use std::ops::Deref;
-        let vscroll = self.vscroll.borrow();
-        let hscroll = self.hscroll.borrow();
-        match events
-            .iter()
-            .rev()
-            .find_map(|event| event.message(vscroll.deref()))
-        {
-            Some(vs) => {
-                let scroll_bar::Message::Changed(ev) = vs;
-                self.position.y = *ev as f32;
+        ControlFlow::Continue
+    }
+
+    fn on_input(&mut self, ctx: &Context, input: &mut RoutedInput, _events: &mut Events) {
+        if input.phase == Phase::Capture || ctx.is_disabled(self) {
+            return;
+        }
+        let scrolled = match input.input {
+            Input::MouseWheel(ev) => {
+                let a = ctx.default_font.as_ref().map(|df| df.size).unwrap_or(1.0);
+                self.scroll(0.0, a * ev.distance)
             }
-            _ => {
-                match events
-                    .iter()
-                    .rev()
-                    .find_map(|event| event.message(hscroll.deref()))
-                {
-                    Some(hs) => {
-                        let scroll_bar::Message::Changed(ev) = hs;
-                        self.position.x = *ev as f32;
-                    }
-                    _ => {}
+            Input::Gesture(ev) => match ev.kind {
+                GestureKind::Pan { dx, dy } | GestureKind::Scroll { dx, dy } => {
+                    self.scroll(-dx, -dy)
                 }
-            }
+                GestureKind::Fling { vx, vy } => {
+                    self.kinetic.start(ctx, self.id, -vx, -vy);
+                    input.stop_propagation();
+                    return;
+                }
+                _ => return,
+            },
+            _ => return,
+        };
+        self.kinetic.stop();
+        if scrolled {
+            ctx.invalidate(self);
         }
-        ControlFlow::Continue
+        input.stop_propagation();
     }
 
     fn apply(&mut self, funcs: &mut ApplyFuncs) {
@@ -172,8 +193,10 @@ impl Widget for InnerFrame {
         {
             let mut hscroll = self.hscroll.borrow_mut();
             let mut vscroll = self.vscroll.borrow_mut();
-            hscroll.thumb.len = viewport.width.ceil() as usize;
-            vscroll.thumb.len = viewport.height.ceil() as usize;
+            hscroll.thumb.len = viewport.width;
+            vscroll.thumb.len = viewport.height;
+            hscroll.advance(0.0);
+            vscroll.advance(0.0);
         }
         result.push(
             &lc,
@@ -195,12 +218,20 @@ impl Widget for InnerFrame {
                 lc.layer,
             ),
         );
-        let mut position = LogicalPosition::new(-self.position.x, -self.position.y);
+        let target = LogicalPosition::new(
+            self.hscroll.borrow().current(),
+            self.vscroll.borrow().current(),
+        );
+        let scroll = lc
+            .ctx
+            .animate(self, "scroll", target, &self.scroll_transition);
+        let mut position = LogicalPosition::new(-scroll.x, -scroll.y);
         for child in self.children.iter() {
-            let size = child.size(&lc);
+            let size = lc.child_size(child.as_ref());
             let range = position.x + size.width >= 0.0 && position.y + size.height >= 0.0;
             if range {
-                child.layout(
+                result.layout_child(
+                    child.as_ref(),
                     lc.next(
                         self,
                         LogicalRect::from_position_size(
@@ -213,7 +244,6 @@ impl Widget for InnerFrame {
                         lc.layer,
                         lc.selected,
                     ),
-                    result,
                 );
             }
             position.y += size.height;
@@ -0,0 +1,38 @@
+use super::*;
+use std::time::Instant;
+
+const FRICTION: f32 = 4.0;
+const MIN_VELOCITY: f32 = 20.0;
+
+#[derive(Debug, Default)]
+pub(crate) struct Kinetic {
+    velocity: (f32, f32),
+    last: Option<Instant>,
+}
+
+impl Kinetic {
+    pub fn start(&mut self, ctx: &Context, owner: Id, vx: f32, vy: f32) {
+        self.velocity = (vx, vy);
+        self.last = Some(ctx.now());
+        ctx.request_wake_up(&owner, ctx.now() + FRAME_INTERVAL);
+    }
+
+    pub fn stop(&mut self) {
+        self.last = None;
+    }
+
+    pub fn step(&mut self, ctx: &Context, owner: Id) -> Option<(f32, f32)> {
+        let last = self.last?;
+        let now = ctx.now();
+        let decay = (-FRICTION * now.duration_since(last).as_secs_f32()).exp();
+        let (vx, vy) = self.velocity;
+        self.velocity = (vx * decay, vy * decay);
+        if self.velocity.0.hypot(self.velocity.1) < MIN_VELOCITY {
+            self.last = None;
+        } else {
+            self.last = Some(now);
+            ctx.request_wake_up(&owner, now + FRAME_INTERVAL);
+        }
+        Some((vx * (1.0 - decay) / FRICTION, vy * (1.0 - decay) / FRICTION))
+    }
+}
@@ -39,10 +39,12 @@ impl Widget for Label {
     }
 
     fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
+        let style = ctx.ctx.style(self, "label");
         let font = self
             .style
             .font
             .as_ref()
+            .or(style.font.as_ref())
             .unwrap_or_else(|| ctx.ctx.default_font.as_ref().unwrap());
         let shape = bounding_box_with_str(ctx.ctx, font, &self.text);
         LogicalSize::new(shape.right - shape.left, shape.bottom - shape.top)
@@ -52,7 +54,19 @@ impl Widget for Label {
         SizeTypes::fix()
     }
 
+    fn baseline(&self, lc: &LayoutContext) -> Option<f32> {
+        let style = lc.ctx.style(self, "label");
+        let font = self
+            .style
+            .font
+            .as_ref()
+            .or(style.font.as_ref())
+            .or(lc.ctx.default_font.as_ref())?;
+        Some(font.ascent())
+    }
+
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let style = lc.ctx.style(self, "label");
         let size = self.size(&lc);
         result.push(
             &lc,
@@ -64,12 +78,14 @@ impl Widget for Label {
                 self.style
                     .font
                     .as_ref()
+                    .or(style.font.as_ref())
                     .or(lc.ctx.default_font.as_ref())
                     .cloned(),
                 self.text.clone(),
                 lc.layer,
                 false,
-            ),
+            )
+            .with_style(&style, lc.state(self, WidgetState::None), false),
         );
     }
 }
@@ -1,10 +1,15 @@
 pub mod button;
+pub mod canvas;
 pub mod check_box;
 pub mod containers;
 pub mod dropdown_box;
+pub mod image;
 pub mod inner_frame;
+mod kinetic;
 pub mod label;
 pub mod list_box;
+pub mod menu;
+pub mod menu_bar;
 pub mod pane;
 pub mod scroll_bar;
 pub mod slider;
@@ -12,12 +17,16 @@ pub mod text;
 pub mod text_box;
 
 pub use button::Button;
+pub use canvas::Canvas;
 pub use check_box::CheckBox;
 pub use containers::*;
 pub use dropdown_box::DropdownBox;
+pub use image::Image;
 pub use inner_frame::InnerFrame;
 pub use label::Label;
 pub use list_box::ListBox;
+pub use menu::Menu;
+pub use menu_bar::MenuBar;
 pub use pane::{HorizontalPanes, VerticalPanes};
 pub use scroll_bar::{HScrollBar, VScrollBar};
 pub use slider::Slider;
@@ -25,3 +34,4 @@ pub use text::Text;
 pub use text_box::TextBox;
 
 use glane_core::*;
+use kinetic::Kinetic;
@@ -1,28 +1,33 @@
 use super::*;
+use std::any::Any;
 use std::cell::{Cell, RefCell};
 
-#[derive(Debug)]
-pub struct Style {
-    pub padding: LogicalRect<f32>,
-}
+const PADDING: LogicalRect<f32> = LogicalRect::new(5.0, 2.0, 5.0, 2.0);
 
-impl Default for Style {
-    fn default() -> Self {
-        Self {
-            padding: LogicalRect::new(5.0, 2.0, 5.0, 2.0),
-        }
-    }
+#[derive(Default, Debug)]
+pub struct Style {
+    pub padding: Option<LogicalRect<f32>>,
+    pub scroll_transition: Transition,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Message {
     Selected(usize),
+    Activated(usize),
+    Moved { from: usize, to: usize },
+}
+
+#[derive(Debug)]
+struct Reorder {
+    list: Id,
+    index: usize,
 }
 
 #[derive(Debug)]
 struct Child {
     object: Box<dyn Widget>,
     rect: Cell<Option<LogicalRect<f32>>>,
+    preview: Option<String>,
 }
 
 impl Child {
@@ -30,6 +35,7 @@ impl Child {
         Self {
             object: Box::new(object),
             rect: Cell::new(None),
+            preview: None,
         }
     }
 }
@@ -44,6 +50,9 @@ pub struct ListBox {
     selected: Option<usize>,
     widget_state: WidgetState,
     min_height: Cell<f32>,
+    pressed: Option<(usize, LogicalPosition<f32>)>,
+    kinetic: Kinetic,
+    pub reorderable: bool,
 }
 
 impl ListBox {
@@ -53,19 +62,22 @@ impl ListBox {
             id: Id::new(),
             style: Default::default(),
             children: vec![],
-            vscroll: RefCell::new(VScrollBar::new(0, 0)),
+            vscroll: RefCell::new(VScrollBar::new(0.0, 0.0)),
             first_view_element: Cell::new(0),
             selected: None,
             widget_state: WidgetState::None,
             min_height: Cell::new(f32::MAX),
+            pressed: None,
+            kinetic: Kinetic::default(),
+            reorderable: false,
         }
     }
 
     #[inline]
     pub fn clear(&mut self) {
         let mut vscroll = self.vscroll.borrow_mut();
-        vscroll.len = 0;
-        vscroll.thumb.len = 0;
+        vscroll.len = 0.0;
+        vscroll.thumb.len = 0.0;
         self.first_view_element.set(0);
         self.selected = None;
         self.children.clear();
@@ -91,6 +103,40 @@ impl ListBox {
         self.selected.map(|i| self.children[i].object.as_ref())
     }
 
+    fn index_at(&self, position: &LogicalPosition<f32>) -> Option<usize> {
+        self.children.iter().position(|child| {
+            child
+                .rect
+                .get()
+                .is_some_and(|r| r.top <= position.y && position.y < r.bottom)
+        })
+    }
+
+    #[inline]
+    pub fn set_preview(&mut self, object: &impl HasId, text: impl Into<String>) {
+        let id = object.id();
+        if let Some(child) = self.children.iter_mut().find(|c| c.object.id() == id) {
+            child.preview = Some(text.into());
+        }
+    }
+
+    fn preview_text(&self, index: usize) -> Option<String> {
+        let child = self.children.get(index)?;
+        if let Some(preview) = child.preview.as_ref() {
+            return Some(preview.clone());
+        }
+        let object: &dyn Any = child.object.as_ref();
+        object
+            .downcast_ref::<Text>()
+            .map(|text| text.text.clone())
+            .or_else(|| {
+                object
+                    .downcast_ref::<Label>()
+                    .map(|label| label.text.clone())
+            })
+            .or_else(|| Some(format!("#{}", index + 1)))
+    }
+
     #[inline]
     pub fn erase_selected(&mut self) {
         if let Some(index) = self.selected {
@@ -107,6 +153,9 @@ impl HasId for ListBox {
 
 impl Widget for ListBox {
     fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        if ctx.is_disabled(self) {
+            return ControlFlow::Continue;
+        }
         let mut layout = ctx.find_layout(self);
         let Some(area) = layout.find(|l| matches!(&**l, LayoutElement::StartClipping(_))) else {
             return ControlFlow::Continue;
@@ -120,6 +169,7 @@ impl Widget for ListBox {
                     && m.button_state == ButtonState::Pressed
                     && area.rect().is_crossing(&m.mouse_state.position);
                 if cond {
+                    self.kinetic.stop();
                     events.push(self, SetFocus);
                     let mut i = self.first_view_element.get();
                     let mut height = 0.0;
@@ -135,15 +185,37 @@ impl Widget for ListBox {
                         });
                         if child_rect.is_some_and(|r| r.contains(&m.mouse_state.position)) {
                             events.push_message(self, Message::Selected(i));
+                            if m.click_count == 2 {
+                                events.push_message(self, Message::Activated(i));
+                            }
                             self.selected = Some(i);
+                            self.pressed = Some((i, m.mouse_state.position));
                             break;
                         }
                         i += 1;
                         height += child_rect.map_or(0.0, |r| r.size().height);
                     }
+                } else if m.button == MouseButton::Left && m.button_state == ButtonState::Released {
+                    self.pressed = None;
                 }
             }
             Input::CursorMoved(m) => {
+                if let Some((index, origin)) = self.pressed
+                    && self.reorderable
+                    && m.mouse_state.buttons.contains(MouseButton::Left)
+                    && ((m.mouse_state.position.x - origin.x).abs() > 4.0
+                        || (m.mouse_state.position.y - origin.y).abs() > 4.0)
+                {
+                    self.pressed = None;
+                    let mut start = StartDrag::new(Reorder {
+                        list: self.id,
+                        index,
+                    });
+                    if let Some(text) = self.preview_text(index) {
+                        start = start.with_preview(text);
+                    }
+                    events.push(self, start);
+                }
                 if area.rect().is_crossing(&m.mouse_state.position) {
                     if m.mouse_state.buttons.contains(MouseButton::Left) {
                         self.widget_state = events.push_state_changed(
@@ -160,12 +232,15 @@ impl Widget for ListBox {
                         events.push_state_changed(self, WidgetState::None, self.widget_state);
                 }
             }
-            Input::MouseWheel(m) => {
-                if area.rect().is_crossing(&m.mouse_state.position)
-                    && m.axis == MouseWheelAxis::Vertical
-                {
+            Input::Tick => {
+                if let Some((_, dy)) = self.kinetic.step(ctx, self.id) {
                     let mut vbar = self.vscroll.borrow_mut();
-                    vbar.advance(self.min_height.get() as isize * m.distance as isize);
+                    let prev = vbar.current();
+                    vbar.advance(dy);
+                    if vbar.current() == prev {
+                        self.kinetic.stop();
+                    }
+                    ctx.invalidate(self);
                 }
             }
             _ => {}
@@ -174,6 +249,60 @@ impl Widget for ListBox {
         vscroll.input(ctx, input, events)
     }
 
+    fn on_input(&mut self, ctx: &Context, input: &mut RoutedInput, _events: &mut Events) {
+        if input.phase == Phase::Capture || ctx.is_disabled(self) {
+            return;
+        }
+        match input.input {
+            Input::MouseWheel(m) if m.axis == MouseWheelAxis::Vertical => {
+                let mut vbar = self.vscroll.borrow_mut();
+                vbar.advance(self.min_height.get() * m.distance);
+            }
+            Input::Gesture(g) => match g.kind {
+                GestureKind::Pan { dy, .. } | GestureKind::Scroll { dy, .. } => {
+                    self.vscroll.borrow_mut().advance(-dy);
+                }
+                GestureKind::Fling { vy, .. } => {
+                    self.kinetic.start(ctx, self.id, 0.0, -vy);
+                    input.stop_propagation();
+                    return;
+                }
+                _ => return,
+            },
+            _ => return,
+        }
+        self.kinetic.stop();
+        ctx.invalidate(self);
+        input.stop_propagation();
+    }
+
+    fn on_drag(&mut self, _ctx: &Context, drag: &mut DragEvent, events: &mut Events) {
+        if drag.payload::<Reorder>().is_none_or(|r| r.list != self.id) {
+            return;
+        }
+        match drag.kind {
+            DragKind::Enter | DragKind::Over => drag.accept(),
+            DragKind::Drop => {
+                let Some(Reorder { index: from, .. }) = drag.take_payload::<Reorder>() else {
+                    return;
+                };
+                let len = self.children.len();
+                if from >= len {
+                    return;
+                }
+                let to = self.index_at(&drag.position).unwrap_or(len - 1);
+                if from == to {
+                    return;
+                }
+                let child = self.children.remove(from);
+                self.children.insert(to, child);
+                self.selected = Some(to);
+                events.push_message(self, Message::Moved { from, to });
+            }
+            _ => {}
+        }
+    }
+
     fn apply(&mut self, funcs: &mut ApplyFuncs) {
         funcs.apply(self);
         for child in self.children.iter_mut() {
@@ -190,36 +319,46 @@ impl Widget for ListBox {
     }
 
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let state = lc.state(self, self.widget_state);
+        result.push_focusable(&lc, self);
+        let style = lc.ctx.style(self, "list_box");
         result.push(
             &lc,
             LayoutElement::start_clipping(self, lc.rect, &lc.ancestors, lc.layer),
         );
         result.push(
             &lc,
-            LayoutElement::area(
-                self,
-                self.widget_state,
-                lc.rect,
-                &lc.ancestors,
-                lc.layer,
-                false,
-            ),
+            LayoutElement::area(self, state, lc.rect, &lc.ancestors, lc.layer, false)
+                .with_style(&style, state, false),
         );
-        let current = self.vscroll.borrow().current() as f32;
+        let padding = self.style.padding.or(style.padding).unwrap_or(PADDING);
         let padding_rect = LogicalRect::new(
-            lc.rect.left + self.style.padding.left,
-            lc.rect.top + self.style.padding.top,
-            lc.rect.right - self.style.padding.right,
-            lc.rect.bottom - self.style.padding.bottom,
+            lc.rect.left + padding.left,
+            lc.rect.top + padding.top,
+            lc.rect.right - padding.right,
+            lc.rect.bottom - padding.bottom,
         );
         let viewport = padding_rect;
+        let sizes = self
+            .children
+            .iter()
+            .map(|child| lc.child_size(child.object.as_ref()))
+            .collect::<Vec<_>>();
+        let total_height = sizes.iter().map(|size| size.height).sum::<f32>();
+        let target = {
+            let mut bar = self.vscroll.borrow_mut();
+            bar.len = total_height;
+            bar.thumb.len = total_height.min(viewport.size().height);
+            bar.advance(0.0);
+            bar.current()
+        };
+        let current = lc
+            .ctx
+            .animate(self, "scroll", target, &self.style.scroll_transition);
         let mut rect = LogicalRect::new(padding_rect.left, padding_rect.top - current, 0.0, 0.0);
-        let mut total_size = LogicalSize::new(padding_rect.size().width, 0.0);
-        let mut thumb_height = 0.0;
         let mut first_view_element = None;
         self.min_height.set(f32::MAX);
-        for (i, child) in self.children.iter().enumerate() {
-            let size = child.object.size(&lc);
+        for (i, (child, size)) in self.children.iter().zip(sizes).enumerate() {
             self.min_height.set(self.min_height.get().min(size.height));
             rect = LogicalRect::from_position_size(
                 rect.left_top(),
@@ -237,35 +376,23 @@ impl Widget for ListBox {
                             &lc.ancestors,
                             lc.layer,
                             true,
-                        ),
+                        )
+                        .with_color(lc.ctx.theme().color("selection")),
                     );
                 }
                 if first_view_element.is_none() {
                     first_view_element = Some(i);
                 }
-                child
-                    .object
-                    .layout(lc.next(self, rect, lc.layer, selected), result);
-                let d = if padding_rect.top > rect.top {
-                    padding_rect.top - rect.top
-                } else if padding_rect.bottom < rect.bottom {
-                    rect.bottom - padding_rect.bottom
-                } else {
-                    0.0
-                };
-                thumb_height += size.height - d;
+                result.layout_child(
+                    child.object.as_ref(),
+                    lc.next(self, rect, lc.layer, selected),
+                );
             }
             child.rect.set(Some(rect));
             rect.top += size.height;
             rect.bottom += size.height;
-            total_size.height += size.height;
         }
         self.first_view_element.set(first_view_element.unwrap_or(0));
-        {
-            let mut bar = self.vscroll.borrow_mut();
-            bar.thumb.len = (thumb_height.ceil()) as usize;
-            bar.len = total_size.height.ceil() as usize;
-        }
         {
             let vscroll = self.vscroll.borrow();
             let size = vscroll.size(&lc);
@@ -273,10 +400,10 @@ impl Widget for ListBox {
                 lc.next(
                     self,
                     LogicalRect::new(
-                        lc.rect.right - size.width - self.style.padding.right,
-                        lc.rect.top + self.style.padding.top,
-                        lc.rect.right - self.style.padding.right,
-                        lc.rect.bottom - self.style.padding.bottom,
+                        lc.rect.right - size.width - padding.right,
+                        lc.rect.top + padding.top,
+                        lc.rect.right - padding.right,
+                        lc.rect.bottom - padding.bottom,
                     ),
                     lc.layer,
                     lc.selected,
@@ -0,0 +1,982 @@
+use super::*;
+use std::time::{Duration, Instant};
+
+const PADDING: LogicalRect<f32> = LogicalRect::new(7.0, 3.0, 7.0, 3.0);
+
+#[derive(Debug)]
+pub struct Style {
+    pub font: Option<Font>,
+    pub padding: Option<LogicalRect<f32>>,
+    pub check_width: f32,
+    pub accelerator_spacing: f32,
+    pub separator_height: f32,
+    pub submenu_delay: Duration,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            font: None,
+            padding: None,
+            check_width: 20.0,
+            accelerator_spacing: 20.0,
+            separator_height: 7.0,
+            submenu_delay: Duration::from_millis(400),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Message {
+    Activated(usize),
+    Closed,
+}
+
+pub(crate) fn parse_mnemonic(text: &str) -> (String, Option<(usize, char)>) {
+    let mut result = String::with_capacity(text.len());
+    let mut mnemonic = None;
+    let mut chars = text.chars().peekable();
+    let mut index = 0;
+    while let Some(c) = chars.next() {
+        if c == '&' {
+            match chars.next() {
+                Some('&') => {}
+                Some(m) => {
+                    if mnemonic.is_none() {
+                        mnemonic = Some((index, m.to_ascii_lowercase()));
+                    }
+                    result.push(m);
+                    index += 1;
+                    continue;
+                }
+                None => break,
+            }
+        }
+        result.push(c);
+        index += 1;
+    }
+    (result, mnemonic)
+}
+
+pub(crate) fn mnemonic_char(vkey: VirtualKey) -> Option<char> {
+    const KEYS: [(VirtualKey, char); 36] = [
+        (VirtualKey::A, 'a'),
+        (VirtualKey::B, 'b'),
+        (VirtualKey::C, 'c'),
+        (VirtualKey::D, 'd'),
+        (VirtualKey::E, 'e'),
+        (VirtualKey::F, 'f'),
+        (VirtualKey::G, 'g'),
+        (VirtualKey::H, 'h'),
+        (VirtualKey::I, 'i'),
+        (VirtualKey::J, 'j'),
+        (VirtualKey::K, 'k'),
+        (VirtualKey::L, 'l'),
+        (VirtualKey::M, 'm'),
+        (VirtualKey::N, 'n'),
+        (VirtualKey::O, 'o'),
+        (VirtualKey::P, 'p'),
+        (VirtualKey::Q, 'q'),
+        (VirtualKey::R, 'r'),
+        (VirtualKey::S, 's'),
+        (VirtualKey::T, 't'),
+        (VirtualKey::U, 'u'),
+        (VirtualKey::V, 'v'),
+        (VirtualKey::W, 'w'),
+        (VirtualKey::X, 'x'),
+        (VirtualKey::Y, 'y'),
+        (VirtualKey::Z, 'z'),
+        (VirtualKey::Key0, '0'),
+        (VirtualKey::Key1, '1'),
+        (VirtualKey::Key2, '2'),
+        (VirtualKey::Key3, '3'),
+        (VirtualKey::Key4, '4'),
+        (VirtualKey::Key5, '5'),
+        (VirtualKey::Key6, '6'),
+        (VirtualKey::Key7, '7'),
+        (VirtualKey::Key8, '8'),
+        (VirtualKey::Key9, '9'),
+    ];
+    KEYS.iter().find(|(k, _)| *k == vkey).map(|(_, c)| *c)
+}
+
+pub(crate) fn underline_rect(
+    ctx: &Context,
+    font: &Font,
+    text: &str,
+    index: usize,
+    origin: LogicalPosition<f32>,
+) -> Option<LogicalRect<f32>> {
+    let c = text.chars().nth(index)?;
+    let prefix = text.chars().take(index).collect::<String>();
+    let prefix_rect = bounding_box_with_str(ctx, font, &prefix);
+    let c_rect = bounding_box_with_str(ctx, font, &c.to_string());
+    let bottom = origin.y + c_rect.bottom;
+    Some(LogicalRect::new(
+        origin.x + prefix_rect.right,
+        bottom - 1.0,
+        origin.x + prefix_rect.right + c_rect.right,
+        bottom,
+    ))
+}
+
+#[derive(Debug)]
+pub struct Underline {
+    id: Id,
+}
+
+impl Underline {
+    pub(crate) fn new() -> Self {
+        Self { id: Id::new() }
+    }
+}
+
+impl HasId for Underline {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for Underline {
+    fn input(&mut self, _ctx: &Context, _input: &Input, _events: &mut Events) -> ControlFlow {
+        ControlFlow::Continue
+    }
+
+    fn apply(&mut self, _funcs: &mut ApplyFuncs) {}
+
+    fn size(&self, _ctx: &LayoutContext) -> LogicalSize<f32> {
+        LogicalSize::new(0.0, 0.0)
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        SizeTypes::fix()
+    }
+
+    fn layout(&self, _lc: LayoutContext, _result: &mut LayoutConstructor) {}
+}
+
+#[derive(Debug)]
+pub enum ItemKind {
+    Command,
+    Check(bool),
+    Separator,
+    SubMenu(Menu),
+}
+
+#[derive(Debug)]
+pub struct Item {
+    id: Id,
+    widget_state: WidgetState,
+    kind: ItemKind,
+    command: usize,
+    text: String,
+    mnemonic: Option<(usize, char)>,
+    check: check_box::Check,
+    underline: Underline,
+    enabled: bool,
+    shortcut: Option<Shortcut>,
+    pub accelerator: Option<String>,
+}
+
+impl Item {
+    fn with_kind(kind: ItemKind, command: usize, text: &str) -> Self {
+        let (text, mnemonic) = parse_mnemonic(text);
+        Self {
+            id: Id::new(),
+            widget_state: WidgetState::None,
+            kind,
+            command,
+            text,
+            mnemonic,
+            check: check_box::Check::new(),
+            underline: Underline::new(),
+            enabled: true,
+            shortcut: None,
+            accelerator: None,
+        }
+    }
+
+    #[inline]
+    pub fn new(command: usize, text: impl AsRef<str>) -> Self {
+        Self::with_kind(ItemKind::Command, command, text.as_ref())
+    }
+
+    #[inline]
+    pub fn check(command: usize, text: impl AsRef<str>, checked: bool) -> Self {
+        Self::with_kind(ItemKind::Check(checked), command, text.as_ref())
+    }
+
+    #[inline]
+    pub fn separator() -> Self {
+        Self::with_kind(ItemKind::Separator, 0, "")
+    }
+
+    #[inline]
+    pub fn submenu(text: impl AsRef<str>, menu: Menu) -> Self {
+        Self::with_kind(ItemKind::SubMenu(menu), 0, text.as_ref())
+    }
+
+    #[inline]
+    pub fn accelerator(mut self, accelerator: impl Into<String>) -> Self {
+        let accelerator = accelerator.into();
+        self.shortcut = accelerator.parse().ok();
+        self.accelerator = Some(accelerator);
+        self
+    }
+
+    #[inline]
+    pub fn shortcut(mut self, shortcut: Shortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self.accelerator = Some(shortcut.to_string());
+        self
+    }
+
+    #[inline]
+    pub fn command(&self) -> usize {
+        self.command
+    }
+
+    #[inline]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    #[inline]
+    pub fn kind(&self) -> &ItemKind {
+        &self.kind
+    }
+
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[inline]
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.widget_state = WidgetState::None;
+        }
+    }
+
+    #[inline]
+    pub fn is_checked(&self) -> bool {
+        matches!(self.kind, ItemKind::Check(true))
+    }
+
+    #[inline]
+    pub fn set_check(&mut self, checked: bool) {
+        if let ItemKind::Check(c) = &mut self.kind {
+            *c = checked;
+        }
+    }
+
+    #[inline]
+    pub fn submenu_mut(&mut self) -> Option<&mut Menu> {
+        match &mut self.kind {
+            ItemKind::SubMenu(menu) => Some(menu),
+            _ => None,
+        }
+    }
+
+    fn is_selectable(&self) -> bool {
+        !matches!(self.kind, ItemKind::Separator)
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.enabled && self.is_selectable()
+    }
+}
+
+impl HasId for Item {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for Item {
+    fn input(&mut self, _ctx: &Context, _input: &Input, _events: &mut Events) -> ControlFlow {
+        ControlFlow::Continue
+    }
+
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        if let ItemKind::SubMenu(menu) = &mut self.kind {
+            menu.apply(funcs);
+        }
+    }
+
+    fn size(&self, _ctx: &LayoutContext) -> LogicalSize<f32> {
+        LogicalSize::new(0.0, 0.0)
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        SizeTypes::fix()
+    }
+
+    fn layout(&self, _lc: LayoutContext, _result: &mut LayoutConstructor) {}
+}
+
+#[derive(Debug)]
+pub struct Menu {
+    id: Id,
+    items: Vec<Item>,
+    position: LogicalPosition<f32>,
+    opened: bool,
+    hover: Option<usize>,
+    hover_since: Option<Instant>,
+    submenu: Option<usize>,
+    pub style: Style,
+}
+
+impl Menu {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            items: vec![],
+            position: LogicalPosition::new(0.0, 0.0),
+            opened: false,
+            hover: None,
+            hover_since: None,
+            submenu: None,
+            style: Style::default(),
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, item: Item) {
+        self.items.push(item);
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    #[inline]
+    pub fn item(&self, index: usize) -> Option<&Item> {
+        self.items.get(index)
+    }
+
+    #[inline]
+    pub fn item_mut(&mut self, index: usize) -> Option<&mut Item> {
+        self.items.get_mut(index)
+    }
+
+    #[inline]
+    pub fn find_command_mut(&mut self, command: usize) -> Option<&mut Item> {
+        for item in self.items.iter_mut() {
+            if matches!(item.kind, ItemKind::Command | ItemKind::Check(_))
+                && item.command == command
+            {
+                return Some(item);
+            }
+            if let ItemKind::SubMenu(menu) = &mut item.kind
+                && let Some(item) = menu.find_command_mut(command)
+            {
+                return Some(item);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    pub fn open(&mut self, position: impl Into<LogicalPosition<f32>>) {
+        self.position = position.into();
+        self.opened = true;
+        self.hover = None;
+        self.hover_since = None;
+        self.submenu = None;
+        for item in self.items.iter_mut() {
+            item.widget_state = WidgetState::None;
+        }
+    }
+
+    #[inline]
+    pub fn close(&mut self) {
+        self.close_submenu();
+        self.opened = false;
+        self.hover = None;
+        self.hover_since = None;
+    }
+
+    #[inline]
+    pub fn is_open(&self) -> bool {
+        self.opened
+    }
+
+    pub(crate) fn has_open_submenu(&self) -> bool {
+        self.submenu.is_some()
+    }
+
+    pub(crate) fn hover_is_submenu(&self) -> bool {
+        self.hover
+            .is_some_and(|i| matches!(self.items[i].kind, ItemKind::SubMenu(_)))
+    }
+
+    pub(crate) fn hover_first(&mut self) {
+        let first = self.items.iter().position(|item| item.is_interactive());
+        self.hover = first;
+        self.hover_since = None;
+        for (i, item) in self.items.iter_mut().enumerate() {
+            item.widget_state = if Some(i) == first {
+                WidgetState::Hover
+            } else {
+                WidgetState::None
+            };
+        }
+    }
+
+    pub(crate) fn contains(&self, ctx: &Context, position: &LogicalPosition<f32>) -> bool {
+        if !self.opened {
+            return false;
+        }
+        if ctx
+            .find_layout(self)
+            .find_map(|l| l.as_area())
+            .is_some_and(|area| area.rect.contains(position))
+        {
+            return true;
+        }
+        self.submenu
+            .and_then(|i| match &self.items[i].kind {
+                ItemKind::SubMenu(menu) => Some(menu.contains(ctx, position)),
+                _ => None,
+            })
+            .unwrap_or(false)
+    }
+
+    fn close_submenu(&mut self) {
+        if let Some(i) = self.submenu.take()
+            && let ItemKind::SubMenu(menu) = &mut self.items[i].kind
+        {
+            menu.close();
+        }
+    }
+
+    fn open_submenu(&mut self, ctx: &Context, index: usize, keyboard: bool) {
+        if self.submenu == Some(index) {
+            return;
+        }
+        self.close_submenu();
+        let Some(rect) = ctx
+            .find_layout(&self.items[index])
+            .find_map(|l| l.as_area())
+            .map(|area| area.rect)
+        else {
+            return;
+        };
+        if let ItemKind::SubMenu(menu) = &mut self.items[index].kind {
+            menu.open(rect.right_top());
+            if keyboard {
+                menu.hover_first();
+            }
+            self.submenu = Some(index);
+            ctx.invalidate(self);
+        }
+    }
+
+    fn set_hover(&mut self, index: Option<usize>, since: Option<Instant>, events: &mut Events) {
+        if self.hover == index {
+            return;
+        }
+        self.hover = index;
+        self.hover_since = index.and(since);
+        for (i, item) in self.items.iter_mut().enumerate() {
+            let state = if Some(i) == index {
+                WidgetState::Hover
+            } else {
+                WidgetState::None
+            };
+            item.widget_state = events.push_state_changed(item, state, item.widget_state);
+        }
+    }
+
+    fn move_hover(&mut self, forward: bool, events: &mut Events) {
+        let len = self.items.len();
+        if len == 0 {
+            return;
+        }
+        let mut index = self.hover.unwrap_or(if forward { len - 1 } else { 0 });
+        for _ in 0..len {
+            index = if forward {
+                (index + 1) % len
+            } else {
+                (index + len - 1) % len
+            };
+            if self.items[index].is_interactive() {
+                self.set_hover(Some(index), None, events);
+                return;
+            }
+        }
+    }
+
+    fn update_submenu(&mut self, ctx: &Context) {
+        let (Some(hover), Some(since)) = (self.hover, self.hover_since) else {
+            return;
+        };
+        let deadline = since + self.style.submenu_delay;
+        if ctx.now() < deadline {
+            ctx.request_wake_up(self, deadline);
+            return;
+        }
+        if matches!(self.items[hover].kind, ItemKind::SubMenu(_)) {
+            self.open_submenu(ctx, hover, false);
+        } else if self.submenu.is_some() {
+            self.close_submenu();
+            ctx.invalidate(self);
+        }
+    }
+
+    fn activate(&mut self, ctx: &Context, index: usize, keyboard: bool, events: &mut Events) {
+        match &mut self.items[index].kind {
+            ItemKind::Command => {}
+            ItemKind::Check(checked) => {
+                *checked = !*checked;
+            }
+            ItemKind::SubMenu(_) => {
+                self.open_submenu(ctx, index, keyboard);
+                return;
+            }
+            ItemKind::Separator => return,
+        }
+        let command = self.items[index].command;
+        self.close();
+        events.push_message(self, Message::Activated(command));
+        events.push_message(self, Message::Closed);
+    }
+
+    pub(crate) fn activate_shortcut(&mut self, input: &Input) -> Option<usize> {
+        self.items.iter_mut().find_map(|item| {
+            if !item.enabled {
+                return None;
+            }
+            match &mut item.kind {
+                ItemKind::SubMenu(menu) => menu.activate_shortcut(input),
+                ItemKind::Separator => None,
+                kind => {
+                    if !item.shortcut.is_some_and(|s| s.matches(input)) {
+                        return None;
+                    }
+                    if let ItemKind::Check(checked) = kind {
+                        *checked = !*checked;
+                    }
+                    Some(item.command)
+                }
+            }
+        })
+    }
+
+    fn item_at(&self, ctx: &Context, position: &LogicalPosition<f32>) -> Option<usize> {
+        self.items.iter().position(|item| {
+            item.is_interactive()
+                && ctx
+                    .find_layout(item)
+                    .find_map(|l| l.as_area())
+                    .is_some_and(|area| area.rect.contains(position))
+        })
+    }
+
+    fn submenu_input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        let Some(index) = self.submenu else {
+            return ControlFlow::Continue;
+        };
+        let ItemKind::SubMenu(menu) = &mut self.items[index].kind else {
+            return ControlFlow::Continue;
+        };
+        let len = events.len();
+        let ret = menu.input(ctx, input, events);
+        if menu.is_open() {
+            return ret;
+        }
+        if let Some(command) = menu.take_messages(events, len) {
+            self.close();
+            events.push_message(self, Message::Activated(command));
+            events.push_message(self, Message::Closed);
+            return ControlFlow::Break;
+        }
+        self.submenu = None;
+        ctx.invalidate(self);
+        ret
+    }
+
+    pub(crate) fn take_messages(&self, events: &mut Events, begin: usize) -> Option<usize> {
+        let mut activated = None;
+        for i in (begin..events.len()).rev() {
+            let message = events
+                .iter()
+                .nth(i)
+                .and_then(|event| event.message(self).copied());
+            match message {
+                Some(Message::Activated(command)) => {
+                    activated = Some(command);
+                    events.remove(i);
+                }
+                Some(Message::Closed) => {
+                    events.remove(i);
+                }
+                None => {}
+            }
+        }
+        activated
+    }
+}
+
+impl Default for Menu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HasId for Menu {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for Menu {
+    fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        if ctx.is_disabled(self) {
+            return ControlFlow::Continue;
+        }
+        if !self.opened {
+            return ControlFlow::Continue;
+        }
+        if let Some(command) = self.activate_shortcut(input) {
+            self.close();
+            events.push_message(self, Message::Activated(command));
+            events.push_message(self, Message::Closed);
+            return ControlFlow::Break;
+        }
+        if self.submenu_input(ctx, input, events) == ControlFlow::Break {
+            return ControlFlow::Break;
+        }
+        match input {
+            Input::CursorMoved(m) => {
+                let position = &m.mouse_state.position;
+                if self.contains(ctx, position) {
+                    let index = self.item_at(ctx, position);
+                    if index.is_some() || self.submenu.is_none() {
+                        self.set_hover(index, Some(ctx.now()), events);
+                    }
+                    self.update_submenu(ctx);
+                    return ControlFlow::Break;
+                }
+                if self.submenu.is_none() {
+                    self.set_hover(None, None, events);
+                }
+                ControlFlow::Continue
+            }
+            Input::MouseInput(m) => {
+                let position = &m.mouse_state.position;
+                if !self.contains(ctx, position) {
+                    if m.button_state == ButtonState::Pressed {
+                        self.close();
+                        events.push_message(self, Message::Closed);
+                        return ControlFlow::Break;
+                    }
+                    return ControlFlow::Continue;
+                }
+                if m.button == MouseButton::Left
+                    && m.button_state == ButtonState::Released
+                    && let Some(index) = self.item_at(ctx, position)
+                {
+                    self.activate(ctx, index, false, events);
+                }
+                ControlFlow::Break
+            }
+            Input::KeyInput(k) => {
+                if k.key_state != KeyState::Pressed {
+                    return ControlFlow::Break;
+                }
+                match k.vkey {
+                    VirtualKey::Up => self.move_hover(false, events),
+                    VirtualKey::Down => self.move_hover(true, events),
+                    VirtualKey::Right => {
+                        if let Some(index) = self.hover
+                            && matches!(self.items[index].kind, ItemKind::SubMenu(_))
+                        {
+                            self.open_submenu(ctx, index, true);
+                        }
+                    }
+                    VirtualKey::Left | VirtualKey::Esc => {
+                        self.close();
+                        events.push_message(self, Message::Closed);
+                    }
+                    VirtualKey::Enter | VirtualKey::Space => {
+                        if let Some(index) = self.hover {
+                            self.activate(ctx, index, true, events);
+                        }
+                    }
+                    vkey => {
+                        let Some(c) = mnemonic_char(vkey) else {
+                            return ControlFlow::Break;
+                        };
+                        let index = self.items.iter().position(|item| {
+                            item.is_interactive() && item.mnemonic.is_some_and(|(_, m)| m == c)
+                        });
+                        if let Some(index) = index {
+                            self.set_hover(Some(index), None, events);
+                            self.activate(ctx, index, true, events);
+                        }
+                    }
+                }
+                ControlFlow::Break
+            }
+            Input::CharInput(_) => ControlFlow::Break,
+            Input::Tick => {
+                self.update_submenu(ctx);
+                ControlFlow::Continue
+            }
+            _ => ControlFlow::Continue,
+        }
+    }
+
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        funcs.apply(self);
+        for item in self.items.iter_mut() {
+            item.apply(funcs);
+        }
+    }
+
+    fn size(&self, _ctx: &LayoutContext) -> LogicalSize<f32> {
+        LogicalSize::new(0.0, 0.0)
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        SizeTypes::fix()
+    }
+
+    fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        if !self.opened {
+            return;
+        }
+        let style = lc.ctx.style(self, "menu");
+        let Some(font) = self
+            .style
+            .font
+            .as_ref()
+            .or(style.font.as_ref())
+            .or(lc.ctx.default_font.as_ref())
+        else {
+            return;
+        };
+        let padding = self.style.padding.or(style.padding).unwrap_or(PADDING);
+        let line_height = bounding_box_with_str(lc.ctx, font, "").bottom;
+        let arrow_width = line_height / 2.0;
+        let mut text_width = 0.0f32;
+        let mut accelerator_width = 0.0f32;
+        for item in self.items.iter().filter(|item| item.is_selectable()) {
+            text_width = text_width.max(bounding_box_with_str(lc.ctx, font, &item.text).right);
+            if let Some(accelerator) = item.accelerator.as_ref() {
+                accelerator_width =
+                    accelerator_width.max(bounding_box_with_str(lc.ctx, font, accelerator).right);
+            }
+        }
+        let item_height = line_height + padding.top + padding.bottom;
+        let width = padding.left
+            + self.style.check_width
+            + text_width
+            + self.style.accelerator_spacing
+            + accelerator_width
+            + arrow_width
+            + padding.right;
+        let height = self
+            .items
+            .iter()
+            .map(|item| {
+                if item.is_selectable() {
+                    item_height
+                } else {
+                    self.style.separator_height
+                }
+            })
+            .sum::<f32>();
+        let viewport = lc.ctx.viewport;
+        let position = LogicalPosition::new(
+            self.position.x.min(viewport.width - width).max(0.0),
+            self.position.y.min(viewport.height - height).max(0.0),
+        );
+        let layer = lc.layer + 1;
+        let rect = LogicalRect::from_position_size(position, LogicalSize::new(width, height));
+        result.push(
+            &lc,
+            LayoutElement::area(self, WidgetState::None, rect, &lc.ancestors, layer, false)
+                .with_style(&style, WidgetState::None, false),
+        );
+        let item_lc = lc.next(self, rect, layer, false);
+        let mut top = rect.top;
+        for (i, item) in self.items.iter().enumerate() {
+            if !item.is_selectable() {
+                let center = top + self.style.separator_height / 2.0;
+                result.push(
+                    &item_lc,
+                    LayoutElement::area(
+                        item,
+                        WidgetState::None,
+                        LogicalRect::new(
+                            rect.left + padding.left,
+                            center - 0.5,
+                            rect.right - padding.right,
+                            center + 0.5,
+                        ),
+                        &item_lc.ancestors,
+                        layer,
+                        false,
+                    )
+                    .with_style(
+                        &lc.ctx.style(item, "menu.separator"),
+                        WidgetState::None,
+                        false,
+                    ),
+                );
+                top += self.style.separator_height;
+                continue;
+            }
+            let item_rect = LogicalRect::new(rect.left, top, rect.right, top + item_height);
+            let item_state = if item.enabled {
+                item_lc.state(item, item.widget_state)
+            } else {
+                WidgetState::Disabled
+            };
+            let item_style =
+                lc.ctx
+                    .animate_style(item, &lc.ctx.style(item, "menu.item"), item_state, false);
+            let foreground = item_style.foreground(item_state, false);
+            result.push(
+                &item_lc,
+                LayoutElement::area(
+                    item,
+                    item_state,
+                    item_rect,
+                    &item_lc.ancestors,
+                    layer,
+                    false,
+                )
+                .with_style(&item_style, item_state, false),
+            );
+            let text_top = top + padding.top;
+            if item.is_checked() {
+                let size = self.style.check_width.min(line_height) - 4.0;
+                let check_rect = LogicalRect::from_position_size(
+                    (
+                        rect.left + padding.left,
+                        text_top + (line_height - size) / 2.0,
+                    ),
+                    (size, size),
+                );
+                result.push(
+                    &item_lc,
+                    LayoutElement::path(
+                        &item.check,
+                        item_state,
+                        &item_lc.ancestors,
+                        Path::check_mark(&check_rect),
+                        layer,
+                    )
+                    .with_style(
+                        &lc.ctx.style(&item.check, "check_box.check"),
+                        item_state,
+                        false,
+                    ),
+                );
+            }
+            let text_left = rect.left + padding.left + self.style.check_width;
+            let text_rect = bounding_box_with_str(item_lc.ctx, font, &item.text);
+            result.push(
+                &item_lc,
+                LayoutElement::text(
+                    item,
+                    item_state,
+                    LogicalRect::from_position_size(
+                        (text_left, text_top),
+                        (text_rect.right, line_height),
+                    ),
+                    &item_lc.ancestors,
+                    Some(font.clone()),
+                    item.text.clone(),
+                    layer,
+                    false,
+                )
+                .with_style(&item_style, item_state, false),
+            );
+            if let Some((index, _)) = item.mnemonic
+                && let Some(underline) = underline_rect(
+                    item_lc.ctx,
+                    font,
+                    &item.text,
+                    index,
+                    LogicalPosition::new(text_left, text_top),
+                )
+            {
+                result.push(
+                    &item_lc,
+                    LayoutElement::area(
+                        &item.underline,
+                        item_state,
+                        underline,
+                        &item_lc.ancestors,
+                        layer,
+                        false,
+                    )
+                    .with_style(
+                        &lc.ctx.style(&item.underline, "menu.underline"),
+                        item_state,
+                        false,
+                    ),
+                );
+            }
+            let arrow_left = rect.right - padding.right - arrow_width;
+            if let Some(accelerator) = item.accelerator.as_ref() {
+                let accelerator_rect = bounding_box_with_str(item_lc.ctx, font, accelerator);
+                result.push(
+                    &item_lc,
+                    LayoutElement::text(
+                        item,
+                        item_state,
+                        LogicalRect::from_position_size(
+                            (arrow_left - accelerator_rect.right, text_top),
+                            (accelerator_rect.right, line_height),
+                        ),
+                        &item_lc.ancestors,
+                        Some(font.clone()),
+                        accelerator.clone(),
+                        layer,
+                        false,
+                    )
+                    .with_style(&item_style, item_state, false),
+                );
+            }
+            if let ItemKind::SubMenu(menu) = &item.kind {
+                let arrow_rect = LogicalRect::from_position_size(
+                    (arrow_left, text_top + (line_height - arrow_width) / 2.0),
+                    (arrow_width, arrow_width),
+                );
+                result.push(
+                    &item_lc,
+                    LayoutElement::path(
+                        item,
+                        item_state,
+                        &item_lc.ancestors,
+                        Path::chevron_right(&arrow_rect),
+                        layer,
+                    )
+                    .with_stroke(foreground, 1.5),
+                );
+                if self.submenu == Some(i) {
+                    menu.layout(item_lc.next(item, item_rect, layer, false), result);
+                }
+            }
+            top += item_height;
+        }
+    }
+}
+
+impl WidgetMessage for Menu {
+    type Message = Message;
+}
@@ -0,0 +1,505 @@
+use super::*;
+use menu::{Underline, mnemonic_char, parse_mnemonic, underline_rect};
+
+const PADDING: LogicalRect<f32> = LogicalRect::new(7.0, 3.0, 7.0, 3.0);
+
+#[derive(Default, Debug)]
+pub struct Style {
+    pub font: Option<Font>,
+    pub padding: Option<LogicalRect<f32>>,
+}
+
+#[derive(Debug)]
+pub struct Title {
+    id: Id,
+    widget_state: WidgetState,
+    text: String,
+    mnemonic: Option<(usize, char)>,
+    underline: Underline,
+    menu: Menu,
+}
+
+impl Title {
+    fn new(text: &str, menu: Menu) -> Self {
+        let (text, mnemonic) = parse_mnemonic(text);
+        Self {
+            id: Id::new(),
+            widget_state: WidgetState::None,
+            text,
+            mnemonic,
+            underline: Underline::new(),
+            menu,
+        }
+    }
+
+    #[inline]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl HasId for Title {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for Title {
+    fn input(&mut self, _ctx: &Context, _input: &Input, _events: &mut Events) -> ControlFlow {
+        ControlFlow::Continue
+    }
+
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        self.menu.apply(funcs);
+    }
+
+    fn size(&self, _ctx: &LayoutContext) -> LogicalSize<f32> {
+        LogicalSize::new(0.0, 0.0)
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        SizeTypes::fix()
+    }
+
+    fn layout(&self, _lc: LayoutContext, _result: &mut LayoutConstructor) {}
+}
+
+#[derive(Debug)]
+pub struct MenuBar {
+    id: Id,
+    titles: Vec<Title>,
+    hover: Option<usize>,
+    opened: Option<usize>,
+    focused: Option<usize>,
+    alt_pressed: bool,
+    alt_used: bool,
+    pub style: Style,
+}
+
+impl MenuBar {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            titles: vec![],
+            hover: None,
+            opened: None,
+            focused: None,
+            alt_pressed: false,
+            alt_used: false,
+            style: Style::default(),
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, text: impl AsRef<str>, menu: Menu) {
+        self.titles.push(Title::new(text.as_ref(), menu));
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.titles.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.titles.is_empty()
+    }
+
+    #[inline]
+    pub fn menu_mut(&mut self, index: usize) -> Option<&mut Menu> {
+        self.titles.get_mut(index).map(|title| &mut title.menu)
+    }
+
+    #[inline]
+    pub fn find_command_mut(&mut self, command: usize) -> Option<&mut menu::Item> {
+        self.titles
+            .iter_mut()
+            .find_map(|title| title.menu.find_command_mut(command))
+    }
+
+    fn title_at(&self, ctx: &Context, position: &LogicalPosition<f32>) -> Option<usize> {
+        self.titles.iter().position(|title| {
+            ctx.find_layout(title)
+                .find_map(|l| l.as_area())
+                .is_some_and(|area| area.rect.contains(position))
+        })
+    }
+
+    fn update_states(&mut self, events: &mut Events) {
+        for (i, title) in self.titles.iter_mut().enumerate() {
+            let state = if self.opened == Some(i) {
+                WidgetState::Pressed
+            } else if self.focused == Some(i) || self.hover == Some(i) {
+                WidgetState::Hover
+            } else {
+                WidgetState::None
+            };
+            title.widget_state = events.push_state_changed(title, state, title.widget_state);
+        }
+    }
+
+    fn open(&mut self, ctx: &Context, index: usize, keyboard: bool, events: &mut Events) {
+        self.close_menu();
+        let Some(rect) = ctx
+            .find_layout(&self.titles[index])
+            .find_map(|l| l.as_area())
+            .map(|area| area.rect)
+        else {
+            return;
+        };
+        let menu = &mut self.titles[index].menu;
+        menu.open(rect.left_bottom());
+        if keyboard {
+            menu.hover_first();
+            self.focused = Some(index);
+        }
+        self.opened = Some(index);
+        ctx.invalidate(self);
+        self.update_states(events);
+    }
+
+    fn close_menu(&mut self) {
+        if let Some(i) = self.opened.take() {
+            self.titles[i].menu.close();
+        }
+    }
+
+    fn find_mnemonic(&self, vkey: VirtualKey) -> Option<usize> {
+        let c = mnemonic_char(vkey)?;
+        self.titles
+            .iter()
+            .position(|title| title.mnemonic.is_some_and(|(_, m)| m == c))
+    }
+
+    fn menu_input(
+        &mut self,
+        ctx: &Context,
+        index: usize,
+        input: &Input,
+        events: &mut Events,
+    ) -> ControlFlow {
+        let len = self.titles.len();
+        match input {
+            Input::KeyInput(k)
+                if k.key_state == KeyState::Pressed
+                    && !self.titles[index].menu.has_open_submenu() =>
+            {
+                match k.vkey {
+                    VirtualKey::Left => {
+                        self.open(ctx, (index + len - 1) % len, true, events);
+                        return ControlFlow::Break;
+                    }
+                    VirtualKey::Right if !self.titles[index].menu.hover_is_submenu() => {
+                        self.open(ctx, (index + 1) % len, true, events);
+                        return ControlFlow::Break;
+                    }
+                    _ => {}
+                }
+            }
+            Input::MouseInput(m)
+                if m.button == MouseButton::Left && m.button_state == ButtonState::Pressed =>
+            {
+                if let Some(i) = self.title_at(ctx, &m.mouse_state.position) {
+                    if i == index {
+                        self.close_menu();
+                        self.focused = None;
+                        events.push_message(self, menu::Message::Closed);
+                        self.update_states(events);
+                    } else {
+                        self.open(ctx, i, false, events);
+                    }
+                    return ControlFlow::Break;
+                }
+            }
+            Input::CursorMoved(m) => {
+                if let Some(i) = self.title_at(ctx, &m.mouse_state.position) {
+                    if i != index {
+                        let keyboard = self.focused.is_some();
+                        self.open(ctx, i, keyboard, events);
+                    }
+                    return ControlFlow::Break;
+                }
+            }
+            _ => {}
+        }
+        let begin = events.len();
+        let menu = &mut self.titles[index].menu;
+        let ret = menu.input(ctx, input, events);
+        if !menu.is_open() {
+            if let Some(command) = menu.take_messages(events, begin) {
+                events.push_message(self, menu::Message::Activated(command));
+            }
+            self.opened = None;
+            self.focused = None;
+            events.push_message(self, menu::Message::Closed);
+            self.update_states(events);
+        }
+        ret
+    }
+}
+
+impl Default for MenuBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HasId for MenuBar {
+    fn id(&self) -> Id {
+        self.id
+    }
+}
+
+impl Widget for MenuBar {
+    fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        if ctx.is_disabled(self) {
+            return ControlFlow::Continue;
+        }
+        if let Some(index) = self.opened {
+            return self.menu_input(ctx, index, input, events);
+        }
+        if let Some(command) = self
+            .titles
+            .iter_mut()
+            .find_map(|title| title.menu.activate_shortcut(input))
+        {
+            events.push_message(self, menu::Message::Activated(command));
+            return ControlFlow::Break;
+        }
+        match input {
+            Input::CursorMoved(m) => {
+                self.hover = self.title_at(ctx, &m.mouse_state.position);
+                self.update_states(events);
+            }
+            Input::CursorLeft(_) => {
+                self.hover = None;
+                self.update_states(events);
+            }
+            Input::MouseInput(m)
+                if m.button == MouseButton::Left && m.button_state == ButtonState::Pressed =>
+            {
+                if let Some(i) = self.title_at(ctx, &m.mouse_state.position) {
+                    self.open(ctx, i, false, events);
+                    return ControlFlow::Break;
+                }
+                if self.focused.take().is_some() {
+                    self.update_states(events);
+                }
+            }
+            Input::KeyInput(k) => {
+                if k.vkey == VirtualKey::Alt {
+                    match k.key_state {
+                        KeyState::Pressed => {
+                            if !self.alt_pressed {
+                                self.alt_pressed = true;
+                                self.alt_used = false;
+                                ctx.invalidate(self);
+                            }
+                        }
+                        KeyState::Released => {
+                            let toggle = self.alt_pressed && !self.alt_used;
+                            if self.alt_pressed {
+                                self.alt_pressed = false;
+                                ctx.invalidate(self);
+                            }
+                            if toggle {
+                                self.focused = if self.focused.is_some() || self.is_empty() {
+                                    None
+                                } else {
+                                    Some(0)
+                                };
+                                self.update_states(events);
+                                return ControlFlow::Break;
+                            }
+                        }
+                    }
+                    return ControlFlow::Continue;
+                }
+                if k.key_state != KeyState::Pressed {
+                    return if self.focused.is_some() {
+                        ControlFlow::Break
+                    } else {
+                        ControlFlow::Continue
+                    };
+                }
+                if self.alt_pressed {
+                    self.alt_used = true;
+                    if let Some(i) = self.find_mnemonic(k.vkey) {
+                        self.open(ctx, i, true, events);
+                        return ControlFlow::Break;
+                    }
+                    return ControlFlow::Continue;
+                }
+                let Some(focused) = self.focused else {
+                    return ControlFlow::Continue;
+                };
+                let len = self.titles.len();
+                match k.vkey {
+                    VirtualKey::Left => {
+                        self.focused = Some((focused + len - 1) % len);
+                        self.update_states(events);
+                    }
+                    VirtualKey::Right => {
+                        self.focused = Some((focused + 1) % len);
+                        self.update_states(events);
+                    }
+                    VirtualKey::Enter | VirtualKey::Space | VirtualKey::Down => {
+                        self.open(ctx, focused, true, events);
+                    }
+                    VirtualKey::Esc => {
+                        self.focused = None;
+                        self.update_states(events);
+                    }
+                    vkey => {
+                        if let Some(i) = self.find_mnemonic(vkey) {
+                            self.open(ctx, i, true, events);
+                        }
+                    }
+                }
+                return ControlFlow::Break;
+            }
+            Input::CharInput(_) if self.focused.is_some() => {
+                return ControlFlow::Break;
+            }
+            _ => {}
+        }
+        ControlFlow::Continue
+    }
+
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        funcs.apply(self);
+        for title in self.titles.iter_mut() {
+            title.apply(funcs);
+        }
+    }
+
+    fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
+        let style = ctx.ctx.style(self, "menu_bar");
+        let Some(font) = self
+            .style
+            .font
+            .as_ref()
+            .or(style.font.as_ref())
+            .or(ctx.ctx.default_font.as_ref())
+        else {
+            return LogicalSize::new(0.0, 0.0);
+        };
+        let padding = self.style.padding.or(style.padding).unwrap_or(PADDING);
+        let line_height = bounding_box_with_str(ctx.ctx, font, "").bottom;
+        LogicalSize::new(
+            ctx.rect.size().width,
+            line_height + padding.top + padding.bottom,
+        )
+    }
+
+    fn size_types(&self) -> SizeTypes {
+        SizeTypes::new(SizeType::Flexible, SizeType::Fix)
+    }
+
+    fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let style = lc.ctx.style(self, "menu_bar");
+        let Some(font) = self
+            .style
+            .font
+            .as_ref()
+            .or(style.font.as_ref())
+            .or(lc.ctx.default_font.as_ref())
+        else {
+            return;
+        };
+        let size = self.size(&lc);
+        let rect = LogicalRect::from_position_size(lc.rect.left_top(), size);
+        result.push(
+            &lc,
+            LayoutElement::area(
+                self,
+                WidgetState::None,
+                rect,
+                &lc.ancestors,
+                lc.layer,
+                false,
+            )
+            .with_style(&style, WidgetState::None, false),
+        );
+        let padding = self.style.padding.or(style.padding).unwrap_or(PADDING);
+        let show_mnemonics = self.alt_pressed || self.focused.is_some();
+        let mut left = rect.left;
+        for (i, title) in self.titles.iter().enumerate() {
+            let text_rect = bounding_box_with_str(lc.ctx, font, &title.text);
+            let title_rect = LogicalRect::new(
+                left,
+                rect.top,
+                left + padding.left + text_rect.right + padding.right,
+                rect.bottom,
+            );
+            let title_state = lc.state(title, lc.state(self, title.widget_state));
+            let title_style = lc.ctx.animate_style(
+                title,
+                &lc.ctx.style(title, "menu_bar.title"),
+                title_state,
+                false,
+            );
+            result.push(
+                &lc,
+                LayoutElement::area(
+                    title,
+                    title_state,
+                    title_rect,
+                    &lc.ancestors,
+                    lc.layer,
+                    false,
+                )
+                .with_style(&title_style, title_state, false),
+            );
+            let text_position =
+                LogicalPosition::new(title_rect.left + padding.left, title_rect.top + padding.top);
+            result.push(
+                &lc,
+                LayoutElement::text(
+                    title,
+                    title_state,
+                    LogicalRect::from_position_size(text_position, text_rect.size()),
+                    &lc.ancestors,
+                    Some(font.clone()),
+                    title.text.clone(),
+                    lc.layer,
+                    false,
+                )
+                .with_style(&title_style, title_state, false),
+            );
+            if show_mnemonics
+                && let Some(underline) = title.mnemonic.and_then(|(index, _)| {
+                    underline_rect(lc.ctx, font, &title.text, index, text_position)
+                })
+            {
+                result.push(
+                    &lc,
+                    LayoutElement::area(
+                        &title.underline,
+                        title_state,
+                        underline,
+                        &lc.ancestors,
+                        lc.layer,
+                        false,
+                    )
+                    .with_style(
+                        &lc.ctx.style(&title.underline, "menu.underline"),
+                        title_state,
+                        false,
+                    ),
+                );
+            }
+            if self.opened == Some(i) {
+                title
+                    .menu
+                    .layout(lc.next(self, title_rect, lc.layer, false), result);
+            }
+            left = title_rect.right;
+        }
+    }
+}
+
+impl WidgetMessage for MenuBar {
+    type Message = menu::Message;
+}
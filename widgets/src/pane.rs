@@ -37,6 +37,9 @@ impl HasId for VerticalPanes {
 
 impl Widget for VerticalPanes {
     fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        if ctx.is_disabled(self) {
+            return ControlFlow::Continue;
+        }
         for pane in self.panes.iter_mut() {
             if pane.input(ctx, input, events) == ControlFlow::Break {
                 return ControlFlow::Break;
@@ -67,8 +70,14 @@ impl Widget for VerticalPanes {
             (lc.rect.left + left.size().width, lc.rect.top),
             lc.rect.right_bottom(),
         );
-        self.panes[0].layout(lc.next(self, left, lc.layer, lc.selected), result);
-        self.panes[1].layout(lc.next(self, right, lc.layer, lc.selected), result);
+        result.layout_child(
+            self.panes[0].as_ref(),
+            lc.next(self, left, lc.layer, lc.selected),
+        );
+        result.layout_child(
+            self.panes[1].as_ref(),
+            lc.next(self, right, lc.layer, lc.selected),
+        );
     }
 }
 
@@ -99,6 +108,9 @@ impl HasId for HorizontalPanes {
 
 impl Widget for HorizontalPanes {
     fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        if ctx.is_disabled(self) {
+            return ControlFlow::Continue;
+        }
         for pane in self.panes.iter_mut() {
             if pane.input(ctx, input, events) == ControlFlow::Break {
                 return ControlFlow::Break;
@@ -129,7 +141,13 @@ impl Widget for HorizontalPanes {
             (lc.rect.left, lc.rect.top + top.size().height),
             lc.rect.right_bottom(),
         );
-        self.panes[0].layout(lc.next(self, top, lc.layer, lc.selected), result);
-        self.panes[1].layout(lc.next(self, bottom, lc.layer, lc.selected), result);
+        result.layout_child(
+            self.panes[0].as_ref(),
+            lc.next(self, top, lc.layer, lc.selected),
+        );
+        result.layout_child(
+            self.panes[1].as_ref(),
+            lc.next(self, bottom, lc.layer, lc.selected),
+        );
     }
 }
@@ -1,9 +1,12 @@
 use super::*;
+use std::time::{Duration, Instant};
 
 #[derive(Debug)]
 pub struct Style {
     pub width: f32,
     pub min_thumb_size: f32,
+    pub repeat_delay: Duration,
+    pub repeat_interval: Duration,
 }
 
 impl Default for Style {
@@ -11,6 +14,8 @@ impl Default for Style {
         Self {
             width: 13.0,
             min_thumb_size: 7.0,
+            repeat_delay: Duration::from_millis(400),
+            repeat_interval: Duration::from_millis(50),
         }
     }
 }
@@ -33,18 +38,18 @@ mod direction {
 
 #[derive(Clone, Copy, Debug)]
 pub enum Message {
-    Changed(usize),
+    Changed(f32),
 }
 
 #[derive(Debug)]
 pub struct Thumb {
     id: Id,
-    pub len: usize,
+    pub len: f32,
     widget_state: WidgetState,
 }
 
 impl Thumb {
-    fn new(len: usize) -> Self {
+    fn new(len: f32) -> Self {
         Self {
             id: Id::new(),
             len,
@@ -64,7 +69,9 @@ impl Widget for Thumb {
         ControlFlow::Continue
     }
 
-    fn apply(&mut self, _funcs: &mut ApplyFuncs) {}
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        funcs.apply(self);
+    }
 
     fn size(&self, _ctx: &LayoutContext) -> LogicalSize<f32> {
         (0.0, 0.0).into()
@@ -77,60 +84,118 @@ impl Widget for Thumb {
     fn layout(&self, _lc: LayoutContext, _result: &mut LayoutConstructor) {}
 }
 
+#[derive(Debug)]
+struct Repeat {
+    position: f32,
+    next: Instant,
+}
+
 #[derive(Debug)]
 pub struct ScrollBar<T: Direction> {
     id: Id,
     style: Style,
-    pub len: usize,
+    pub len: f32,
     pub thumb: Thumb,
-    current: usize,
+    current: f32,
     d: f32,
     min_collision: f32,
+    repeat: Option<Repeat>,
     _direction: std::marker::PhantomData<T>,
 }
 
 impl<T: Direction> ScrollBar<T> {
     #[inline]
-    pub fn new(len: usize, thumb_len: usize) -> Self {
+    pub fn new(len: f32, thumb_len: f32) -> Self {
         Self {
             id: Id::new(),
             style: Style::default(),
             len,
-            current: 0,
+            current: 0.0,
             thumb: Thumb::new(thumb_len),
             d: 0.0,
             min_collision: 15.0,
+            repeat: None,
             _direction: std::marker::PhantomData,
         }
     }
 
     #[inline]
-    pub fn current(&self) -> usize {
+    pub fn current(&self) -> f32 {
         self.current
     }
 
     #[inline]
-    pub fn advance(&mut self, d: isize) {
-        use std::cmp::Ordering;
-        match d.cmp(&0) {
-            Ordering::Less => {
-                let d = -d as usize;
-                if self.current <= d {
-                    self.current = 0;
-                } else {
-                    self.current -= d;
-                }
-            }
-            Ordering::Greater => {
-                let max = self.len - self.thumb.len;
-                let d = d as usize;
-                if self.current + d >= max {
-                    self.current = max;
-                } else {
-                    self.current += d;
-                }
-            }
-            Ordering::Equal => {}
+    pub fn max(&self) -> f32 {
+        (self.len - self.thumb.len).max(0.0)
+    }
+
+    #[inline]
+    pub fn set_current(&mut self, current: f32) {
+        self.current = current.clamp(0.0, self.max());
+    }
+
+    #[inline]
+    pub fn advance(&mut self, d: f32) {
+        self.set_current(self.current + d);
+    }
+
+    fn page_toward(&mut self, position: f32, origin: f32, length: f32, events: &mut Events) -> bool
+    where
+        Self: WidgetMessage<Message = Message>,
+    {
+        if self.len <= 0.0 {
+            return false;
+        }
+        let start = origin + (self.current / self.len) * length;
+        let end = start + (self.thumb.len / self.len) * length;
+        let prev = self.current;
+        if position < start {
+            self.advance(-self.thumb.len);
+        } else if position > end {
+            self.advance(self.thumb.len);
+        }
+        if self.current == prev {
+            return false;
+        }
+        events.push_message(self, Message::Changed(self.current));
+        true
+    }
+
+    fn start_repeat(
+        &mut self,
+        ctx: &Context,
+        position: f32,
+        origin: f32,
+        length: f32,
+        events: &mut Events,
+    ) where
+        Self: WidgetMessage<Message = Message>,
+    {
+        if self.page_toward(position, origin, length, events) {
+            let next = ctx.now() + self.style.repeat_delay;
+            self.repeat = Some(Repeat { position, next });
+            ctx.request_wake_up(&self.id, next);
+        }
+    }
+
+    fn update_repeat(&mut self, ctx: &Context, origin: f32, length: f32, events: &mut Events)
+    where
+        Self: WidgetMessage<Message = Message>,
+    {
+        let Some(repeat) = self.repeat.as_ref() else {
+            return;
+        };
+        if ctx.now() < repeat.next {
+            ctx.request_wake_up(&self.id, repeat.next);
+            return;
+        }
+        let position = repeat.position;
+        if self.page_toward(position, origin, length, events) {
+            let next = ctx.now() + self.style.repeat_interval;
+            self.repeat = Some(Repeat { position, next });
+            ctx.request_wake_up(&self.id, next);
+        } else {
+            self.repeat = None;
         }
     }
 }
@@ -143,6 +208,9 @@ impl<T: Direction> HasId for ScrollBar<T> {
 
 impl Widget for ScrollBar<direction::Vertical> {
     fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        if ctx.is_disabled(self) {
+            return ControlFlow::Continue;
+        }
         let Some(layout) = ctx.find_layout(self).next() else {
             return ControlFlow::Continue;
         };
@@ -156,26 +224,51 @@ impl Widget for ScrollBar<direction::Vertical> {
         let thumb_size = thumb_layout.rect().size();
         match input {
             Input::MouseInput(m) => {
+                let prev = self.thumb.widget_state;
                 if thumb_layout.rect().contains(&m.mouse_state.position) {
                     if m.button == MouseButton::Left && m.button_state == ButtonState::Pressed {
                         self.d = m.mouse_state.position.y - thumb_layout.rect().top;
                         self.thumb.widget_state = WidgetState::Pressed;
+                        events.push(self, CapturePointer);
                     } else {
                         self.thumb.widget_state = WidgetState::Hover;
                     }
                 } else {
                     self.thumb.widget_state = WidgetState::None;
+                    if m.button == MouseButton::Left
+                        && m.button_state == ButtonState::Pressed
+                        && layout.rect().contains(&m.mouse_state.position)
+                    {
+                        self.start_repeat(
+                            ctx,
+                            m.mouse_state.position.y,
+                            layout.rect().top,
+                            size.height,
+                            events,
+                        );
+                    }
+                }
+                if m.button == MouseButton::Left && m.button_state == ButtonState::Released {
+                    self.repeat = None;
+                }
+                if self.thumb.widget_state != prev {
+                    ctx.invalidate(self);
                 }
             }
             Input::CursorMoved(m) => {
+                if let Some(repeat) = self.repeat.as_mut() {
+                    repeat.position = m.mouse_state.position.y;
+                }
                 if self.thumb.widget_state == WidgetState::Pressed {
                     let height = size.height - thumb_size.height;
                     let p = m.mouse_state.position.y - layout.rect().top - self.d;
-                    let current = (self.len - self.thumb.len) as f32 * p / height;
-                    self.current = (current.floor() as usize).min(self.len - self.thumb.len - 1);
+                    self.set_current(self.max() * p / height);
                     events.push_message(self, Message::Changed(self.current))
                 }
             }
+            Input::Tick => {
+                self.update_repeat(ctx, layout.rect().top, size.height, events);
+            }
             _ => {}
         }
         ControlFlow::Continue
@@ -183,6 +276,7 @@ impl Widget for ScrollBar<direction::Vertical> {
 
     fn apply(&mut self, funcs: &mut ApplyFuncs) {
         funcs.apply(self);
+        self.thumb.apply(funcs);
     }
 
     fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
@@ -194,14 +288,13 @@ impl Widget for ScrollBar<direction::Vertical> {
     }
 
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let thumb_state = lc.state(self, self.thumb.widget_state);
         let size = self.size(&lc);
-        let thumb_size = LogicalSize::new(
-            self.style.width,
-            (self.thumb.len as f32 / self.len as f32) * size.height,
-        );
+        let thumb_size =
+            LogicalSize::new(self.style.width, (self.thumb.len / self.len) * size.height);
         let thumb_pt = LogicalPosition::new(
             lc.rect.left,
-            lc.rect.top + (self.current as f32 / self.len as f32) * size.height,
+            lc.rect.top + (self.current / self.len) * size.height,
         );
         let rect = LogicalRect::from_position_size(lc.rect.left_top(), size);
         let thumb_rect = LogicalRect::from_position_size(thumb_pt, thumb_size);
@@ -215,6 +308,7 @@ impl Widget for ScrollBar<direction::Vertical> {
                 thumb_rect.bottom + self.min_collision / 2.0,
             )
         };
+        let thumb_lc = lc.next(self, thumb_rect, lc.layer, lc.selected);
         result.push(
             &lc,
             LayoutElement::area(
@@ -224,26 +318,41 @@ impl Widget for ScrollBar<direction::Vertical> {
                 &lc.ancestors,
                 lc.layer,
                 false,
+            )
+            .with_style(
+                &lc.ctx.style(self, "scroll_bar"),
+                lc.state(self, WidgetState::None),
+                false,
             ),
         );
         result.push(
             &lc,
             LayoutElement::area(
                 &self.thumb,
-                self.thumb.widget_state,
+                thumb_state,
                 thumb_rect,
-                &lc.ancestors,
+                &thumb_lc.ancestors,
                 lc.layer,
                 false,
+            )
+            .with_style(
+                &lc.ctx.animate_style(
+                    &self.thumb,
+                    &lc.ctx.style(&self.thumb, "scroll_bar.thumb"),
+                    thumb_state,
+                    false,
+                ),
+                thumb_state,
+                false,
             ),
         );
         result.push(
             &lc,
             LayoutElement::collision(
                 &self.thumb,
-                self.thumb.widget_state,
+                thumb_state,
                 collision_rect,
-                &lc.ancestors,
+                &thumb_lc.ancestors,
                 lc.layer,
             ),
         );
@@ -252,6 +361,9 @@ impl Widget for ScrollBar<direction::Vertical> {
 
 impl Widget for ScrollBar<direction::Horizontal> {
     fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        if ctx.is_disabled(self) {
+            return ControlFlow::Continue;
+        }
         let Some(layout) = ctx.find_layout(self).next() else {
             return ControlFlow::Continue;
         };
@@ -265,32 +377,58 @@ impl Widget for ScrollBar<direction::Horizontal> {
         let thumb_size = thumb_layout.rect().size();
         match input {
             Input::MouseInput(m) => {
+                let prev = self.thumb.widget_state;
                 if thumb_layout.rect().contains(&m.mouse_state.position) {
                     if m.button == MouseButton::Left && m.button_state == ButtonState::Pressed {
                         self.d = m.mouse_state.position.x - thumb_layout.rect().left;
                         self.thumb.widget_state = WidgetState::Pressed;
+                        events.push(self, CapturePointer);
                     } else {
                         self.thumb.widget_state = WidgetState::Hover;
                     }
                 } else {
                     self.thumb.widget_state = WidgetState::None;
+                    if m.button == MouseButton::Left
+                        && m.button_state == ButtonState::Pressed
+                        && layout.rect().contains(&m.mouse_state.position)
+                    {
+                        self.start_repeat(
+                            ctx,
+                            m.mouse_state.position.x,
+                            layout.rect().left,
+                            size.width,
+                            events,
+                        );
+                    }
+                }
+                if m.button == MouseButton::Left && m.button_state == ButtonState::Released {
+                    self.repeat = None;
+                }
+                if self.thumb.widget_state != prev {
+                    ctx.invalidate(self);
                 }
             }
             Input::CursorMoved(m) => {
+                if let Some(repeat) = self.repeat.as_mut() {
+                    repeat.position = m.mouse_state.position.x;
+                }
                 if self.thumb.widget_state == WidgetState::Pressed {
                     let width = size.width - thumb_size.width;
                     let p = m.mouse_state.position.x - layout.rect().left - self.d;
-                    let current = (self.len - self.thumb.len) as f32 * p / width;
-                    self.current = (current.floor() as usize).min(self.len - self.thumb.len - 1);
+                    self.set_current(self.max() * p / width);
                     events.push_message(self, Message::Changed(self.current));
                 }
             }
+            Input::Tick => {
+                self.update_repeat(ctx, layout.rect().left, size.width, events);
+            }
             _ => {}
         }
         ControlFlow::Continue
     }
     fn apply(&mut self, funcs: &mut ApplyFuncs) {
         funcs.apply(self);
+        self.thumb.apply(funcs);
     }
 
     fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
@@ -302,13 +440,12 @@ impl Widget for ScrollBar<direction::Horizontal> {
     }
 
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let thumb_state = lc.state(self, self.thumb.widget_state);
         let size = self.size(&lc);
-        let thumb_size = LogicalSize::new(
-            (self.thumb.len as f32 / self.len as f32) * size.width,
-            self.style.width,
-        );
+        let thumb_size =
+            LogicalSize::new((self.thumb.len / self.len) * size.width, self.style.width);
         let thumb_pt = LogicalPosition::new(
-            lc.rect.left + (self.current as f32 / self.len as f32) * size.width,
+            lc.rect.left + (self.current / self.len) * size.width,
             lc.rect.top,
         );
         let rect = LogicalRect::from_position_size(lc.rect.left_top(), size);
@@ -323,6 +460,7 @@ impl Widget for ScrollBar<direction::Horizontal> {
                 thumb_rect.bottom,
             )
         };
+        let thumb_lc = lc.next(self, thumb_rect, lc.layer, lc.selected);
         result.push(
             &lc,
             LayoutElement::area(
@@ -332,26 +470,41 @@ impl Widget for ScrollBar<direction::Horizontal> {
                 &lc.ancestors,
                 lc.layer,
                 false,
+            )
+            .with_style(
+                &lc.ctx.style(self, "scroll_bar"),
+                lc.state(self, WidgetState::None),
+                false,
             ),
         );
         result.push(
             &lc,
             LayoutElement::area(
                 &self.thumb,
-                self.thumb.widget_state,
+                thumb_state,
                 thumb_rect,
-                &lc.ancestors,
+                &thumb_lc.ancestors,
                 lc.layer,
                 false,
+            )
+            .with_style(
+                &lc.ctx.animate_style(
+                    &self.thumb,
+                    &lc.ctx.style(&self.thumb, "scroll_bar.thumb"),
+                    thumb_state,
+                    false,
+                ),
+                thumb_state,
+                false,
             ),
         );
         result.push(
             &lc,
             LayoutElement::collision(
                 &self.thumb,
-                self.thumb.widget_state,
+                thumb_state,
                 collision_rect,
-                &lc.ancestors,
+                &thumb_lc.ancestors,
                 lc.layer,
             ),
         );
@@ -27,7 +27,9 @@ impl Widget for Knob {
         ControlFlow::Continue
     }
 
-    fn apply(&mut self, _funcs: &mut ApplyFuncs) {}
+    fn apply(&mut self, funcs: &mut ApplyFuncs) {
+        funcs.apply(self);
+    }
 
     fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
         ctx.rect.size()
@@ -46,6 +48,7 @@ pub struct Slider {
     widget_state: WidgetState,
     knob: Knob,
     pub height: f32,
+    pub ticks: usize,
     current: f32,
     d: f32,
 }
@@ -58,6 +61,7 @@ impl Slider {
             widget_state: WidgetState::None,
             knob: Knob::new(),
             height: 13.0,
+            ticks: 0,
             current: 0.0,
             d: 0.0,
         }
@@ -84,6 +88,9 @@ impl HasId for Slider {
 
 impl Widget for Slider {
     fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        if ctx.is_disabled(self) {
+            return ControlFlow::Continue;
+        }
         let Some(layout) = ctx.find_layout(self).next() else {
             return ControlFlow::Continue;
         };
@@ -93,16 +100,21 @@ impl Widget for Slider {
         let size = layout.rect().size();
         match input {
             Input::MouseInput(m) => {
+                let prev = self.widget_state;
                 if knob.rect().contains(&m.mouse_state.position) {
                     if m.button == MouseButton::Left && m.button_state == ButtonState::Pressed {
                         self.d = m.mouse_state.position.x - knob.rect().left;
                         self.widget_state = WidgetState::Pressed;
+                        events.push(self, CapturePointer);
                     } else {
                         self.widget_state = WidgetState::Hover;
                     }
                 } else {
                     self.widget_state = WidgetState::None;
                 }
+                if self.widget_state != prev {
+                    ctx.invalidate(self);
+                }
             }
             Input::CursorMoved(m) => {
                 if self.widget_state == WidgetState::Pressed {
@@ -111,6 +123,23 @@ impl Widget for Slider {
                     events.push_message(self, Message::Changed(self.current));
                 }
             }
+            Input::KeyInput(k) if ctx.has_focus(self) && k.key_state == KeyState::Pressed => {
+                let step = if self.ticks > 1 {
+                    1.0 / (self.ticks - 1) as f32
+                } else {
+                    0.1
+                };
+                let current = match k.vkey {
+                    VirtualKey::Left | VirtualKey::Down => self.current - step,
+                    VirtualKey::Right | VirtualKey::Up => self.current + step,
+                    VirtualKey::Home => 0.0,
+                    VirtualKey::End => 1.0,
+                    _ => return ControlFlow::Continue,
+                };
+                self.current = current.clamp(0.0, 1.0);
+                ctx.invalidate(self);
+                events.push_message(self, Message::Changed(self.current));
+            }
             _ => {}
         }
         ControlFlow::Continue
@@ -118,6 +147,7 @@ impl Widget for Slider {
 
     fn apply(&mut self, funcs: &mut ApplyFuncs) {
         funcs.apply(self);
+        self.knob.apply(funcs);
     }
 
     fn size(&self, ctx: &LayoutContext) -> LogicalSize<f32> {
@@ -130,6 +160,11 @@ impl Widget for Slider {
     }
 
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let state = lc.state(self, self.widget_state);
+        let track_state = lc.state(self, WidgetState::None);
+        result.push_focusable(&lc, self);
+        let focused = lc.ctx.has_focus(self);
+        let style = lc.ctx.style(self, "slider");
         let size = self.size(&lc);
         let rect = LogicalRect::from_position_size(lc.rect.left_top(), size);
         let knob_rect = LogicalRect::from_position_size(
@@ -145,17 +180,41 @@ impl Widget for Slider {
                 &lc.ancestors,
                 lc.layer,
                 false,
-            ),
+            )
+            .with_style(&style, track_state, false),
         );
+        if self.ticks > 1 {
+            let step = size.width / (self.ticks - 1) as f32;
+            let left = rect.left + self.height / 2.0;
+            let path = (0..self.ticks).fold(Path::new(), |path, i| {
+                let x = left + step * i as f32;
+                path.move_to((x, rect.top)).line_to((x, rect.bottom))
+            });
+            result.push(
+                &lc,
+                LayoutElement::path(self, track_state, &lc.ancestors, path, lc.layer)
+                    .with_stroke(style.foreground(track_state, false), 1.0),
+            );
+        }
         result.push(
             &lc,
             LayoutElement::area(
                 &self.knob,
-                self.widget_state,
+                state,
                 knob_rect,
-                &lc.ancestors,
+                &lc.next(self, knob_rect, lc.layer, lc.selected).ancestors,
                 lc.layer,
                 false,
+            )
+            .with_style(
+                &lc.ctx.animate_style(
+                    &self.knob,
+                    &lc.ctx.style(&self.knob, "slider.knob"),
+                    state,
+                    focused,
+                ),
+                state,
+                focused,
             ),
         );
     }
@@ -2,14 +2,14 @@ use super::*;
 
 #[derive(Default, Debug)]
 pub struct Style {
-    font: Option<Font>,
+    pub font: Option<Font>,
 }
 
 #[derive(Debug)]
 pub struct Text {
     id: Id,
-    style: Style,
     pub text: String,
+    pub style: Style,
 }
 
 impl Text {
@@ -39,10 +39,12 @@ impl Widget for Text {
     }
 
     fn size(&self, lc: &LayoutContext) -> LogicalSize<f32> {
+        let style = lc.ctx.style(self, "text");
         let font = self
             .style
             .font
             .as_ref()
+            .or(style.font.as_ref())
             .unwrap_or_else(|| lc.ctx.default_font.as_ref().unwrap());
         let shape = bounding_box_with_str(lc.ctx, font, &self.text);
         LogicalSize::new(shape.right - shape.left, shape.bottom - shape.top)
@@ -53,6 +55,7 @@ impl Widget for Text {
     }
 
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let style = lc.ctx.style(self, "text");
         let size = self.size(&lc);
         result.push(
             &lc,
@@ -64,12 +67,14 @@ impl Widget for Text {
                 self.style
                     .font
                     .as_ref()
+                    .or(style.font.as_ref())
                     .or(lc.ctx.default_font.as_ref())
                     .cloned(),
                 self.text.clone(),
                 lc.layer,
                 false,
-            ),
+            )
+            .with_style(&style, lc.state(self, WidgetState::None), false),
         );
     }
 }
@@ -1,17 +1,23 @@
 use super::*;
+use std::cell::Cell;
+use std::time::{Duration, Instant};
 use unicode_normalization::UnicodeNormalization;
 
+const PADDING: LogicalRect<f32> = LogicalRect::new(5.0, 3.0, 5.0, 3.0);
+
 #[derive(Debug)]
 pub struct Style {
     pub font: Option<Font>,
-    pub padding: LogicalRect<f32>,
+    pub padding: Option<LogicalRect<f32>>,
+    pub caret_blink_interval: Option<Duration>,
 }
 
 impl Default for Style {
     fn default() -> Self {
         Self {
             font: None,
-            padding: LogicalRect::new(5.0, 3.0, 5.0, 3.0),
+            padding: None,
+            caret_blink_interval: Some(Duration::from_millis(530)),
         }
     }
 }
@@ -30,6 +36,9 @@ pub struct TextBox {
     front_text: Vec<char>,
     back_text: Vec<char>,
     composition: Option<Composition>,
+    caret_origin: Instant,
+    caret_rect: Cell<Option<LogicalRect<f32>>>,
+    read_only: bool,
 }
 
 impl TextBox {
@@ -42,6 +51,22 @@ impl TextBox {
             front_text: vec![],
             back_text: vec![],
             composition: None,
+            caret_origin: Instant::now(),
+            caret_rect: Cell::new(None),
+            read_only: false,
+        }
+    }
+
+    #[inline]
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    #[inline]
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+        if read_only {
+            self.composition = None;
         }
     }
 
@@ -50,6 +75,19 @@ impl TextBox {
         self.front_text.clear();
         self.back_text.clear();
     }
+
+    fn is_caret_visible(&self, ctx: &Context) -> bool {
+        let Some(interval) = self.style.caret_blink_interval else {
+            return true;
+        };
+        if self.composition.is_some() || interval.is_zero() {
+            return true;
+        }
+        let elapsed = ctx.now().saturating_duration_since(self.caret_origin);
+        let phase = elapsed.as_nanos() / interval.as_nanos();
+        ctx.request_wake_up(self, self.caret_origin + interval * (phase as u32 + 1));
+        phase.is_multiple_of(2)
+    }
 }
 
 impl HasId for TextBox {
@@ -60,77 +98,87 @@ impl HasId for TextBox {
 
 impl Widget for TextBox {
     fn input(&mut self, ctx: &Context, input: &Input, events: &mut Events) -> ControlFlow {
+        if ctx.is_disabled(self) {
+            return ControlFlow::Continue;
+        }
+        if !matches!(
+            input,
+            Input::Tick | Input::CursorMoved(_) | Input::CursorLeft(_)
+        ) {
+            self.caret_origin = ctx.now();
+            if ctx.has_focus(self) {
+                ctx.invalidate(self);
+            }
+        }
         match input {
             Input::MouseInput(m) => {
-                if let Some(l) = ctx.layout.iter().find(|l| l.handle().is(self)) {
-                    if l.rect().contains(&m.mouse_state.position) {
-                        events.push(self, SetFocus);
-                    }
+                if let Some(l) = ctx.find_layout(self).next()
+                    && l.rect().contains(&m.mouse_state.position)
+                {
+                    events.push(self, SetFocus);
                 }
             }
-            Input::KeyInput(k) => {
-                if ctx.has_focus(self) && k.key_state == KeyState::Pressed {
-                    match k.vkey {
-                        VirtualKey::Left => {
-                            if let Some(c) = self.front_text.pop() {
-                                self.back_text.push(c);
-                            }
+            Input::KeyInput(k) if ctx.has_focus(self) && k.key_state == KeyState::Pressed => {
+                match k.vkey {
+                    VirtualKey::Left => {
+                        if let Some(c) = self.front_text.pop() {
+                            self.back_text.push(c);
                         }
-                        VirtualKey::Right => {
-                            if let Some(c) = self.back_text.pop() {
-                                self.front_text.push(c);
-                            }
+                    }
+                    VirtualKey::Right => {
+                        if let Some(c) = self.back_text.pop() {
+                            self.front_text.push(c);
                         }
-                        _ => {}
                     }
+                    _ => return ControlFlow::Continue,
                 }
+                ctx.invalidate(self);
             }
-            Input::CharInput(c) => {
-                if ctx.has_focus(self) {
-                    match c {
-                        '\x08' => {
-                            self.front_text.pop();
-                        }
-                        _ if c.is_control() => {
-                            return ControlFlow::Continue;
-                        }
-                        _ => {
-                            self.front_text.extend(c.nfc());
-                        }
+            Input::CharInput(c) if ctx.has_focus(self) && !self.read_only => {
+                match c {
+                    '\x08' => {
+                        self.front_text.pop();
+                    }
+                    _ if c.is_control() => {
+                        return ControlFlow::Continue;
+                    }
+                    _ => {
+                        self.front_text.extend(c.nfc());
                     }
-                    let s = self
-                        .front_text
-                        .iter()
-                        .chain(self.back_text.iter())
-                        .collect::<String>();
-                    events.push(self, Message::Changed(s));
                 }
+                let s = self
+                    .front_text
+                    .iter()
+                    .chain(self.back_text.iter())
+                    .collect::<String>();
+                events.push(self, Message::Changed(s));
             }
             Input::ImeBeginComposition => {
-                let cursor = ctx
-                    .find_layout(self)
-                    .find(|layout| matches!(&**layout, LayoutElement::Cursor(_)));
-                if let Some(l) = cursor {
-                    events.push(self, Message::PositionNotify(l.rect().left_bottom()));
+                if let Some(rect) = self.caret_rect.get() {
+                    events.push(self, Message::PositionNotify(rect.left_bottom()));
                 }
             }
-            Input::ImeUpdateComposition(composition) => {
+            Input::ImeUpdateComposition(composition) if ctx.has_focus(self) && !self.read_only => {
                 self.composition = Some(composition.clone());
+                ctx.invalidate(self);
             }
             Input::ImeEndComposition(result) => {
-                if ctx.has_focus(self) {
-                    if let Some(result) = result {
-                        self.front_text
-                            .append(&mut result.chars().collect::<Vec<_>>());
-                        let s = self
-                            .front_text
-                            .iter()
-                            .chain(self.back_text.iter())
-                            .collect::<String>();
-                        events.push(self, Message::Changed(s));
-                    }
+                if ctx.has_focus(self)
+                    && !self.read_only
+                    && let Some(result) = result
+                {
+                    self.front_text
+                        .append(&mut result.chars().collect::<Vec<_>>());
+                    let s = self
+                        .front_text
+                        .iter()
+                        .chain(self.back_text.iter())
+                        .collect::<String>();
+                    events.push(self, Message::Changed(s));
+                }
+                if self.composition.take().is_some() {
+                    ctx.invalidate(self);
                 }
-                self.composition = None;
             }
             _ => {}
         }
@@ -142,11 +190,14 @@ impl Widget for TextBox {
     }
 
     fn size(&self, lc: &LayoutContext) -> LogicalSize<f32> {
+        let style = lc.ctx.style(self, "text_box");
         let font = self
             .style
             .font
             .as_ref()
+            .or(style.font.as_ref())
             .unwrap_or_else(|| lc.ctx.default_font.as_ref().unwrap());
+        let padding = self.style.padding.or(style.padding).unwrap_or(PADDING);
         let text = self
             .front_text
             .iter()
@@ -156,7 +207,7 @@ impl Widget for TextBox {
         let t = bounding_box_with_str(lc.ctx, font, &text);
         let rect_size = lc.rect.size();
         let width = rect_size.width;
-        let height = t.size().height + self.style.padding.top + self.style.padding.bottom;
+        let height = t.size().height + padding.top + padding.bottom;
         LogicalSize::new(width, height)
     }
 
@@ -164,11 +215,36 @@ impl Widget for TextBox {
         SizeTypes::new(SizeType::Flexible, SizeType::Fix)
     }
 
+    fn baseline(&self, lc: &LayoutContext) -> Option<f32> {
+        let style = lc.ctx.style(self, "text_box");
+        let font = self
+            .style
+            .font
+            .as_ref()
+            .or(style.font.as_ref())
+            .or(lc.ctx.default_font.as_ref())?;
+        let padding = self.style.padding.or(style.padding).unwrap_or(PADDING);
+        Some(padding.top + font.ascent())
+    }
+
     fn layout(&self, lc: LayoutContext, result: &mut LayoutConstructor) {
+        let state = lc.state(self, self.widget_state);
+        result.push_focusable(&lc, self);
         let size = self.size(&lc);
-        let Some(font) = self.style.font.as_ref().or(lc.ctx.default_font.as_ref()) else {
+        let focused = lc.ctx.has_focus(self);
+        let style = lc
+            .ctx
+            .animate_style(self, &lc.ctx.style(self, "text_box"), state, focused);
+        let Some(font) = self
+            .style
+            .font
+            .as_ref()
+            .or(style.font.as_ref())
+            .or(lc.ctx.default_font.as_ref())
+        else {
             return;
         };
+        let padding = self.style.padding.or(style.padding).unwrap_or(PADDING);
         let mut rect = LogicalRect::from_position_size(lc.rect.left_top(), size);
         let clipping_rect = rect;
         result.push(
@@ -177,19 +253,13 @@ impl Widget for TextBox {
         );
         result.push(
             &lc,
-            LayoutElement::area(
-                self,
-                self.widget_state,
-                clipping_rect,
-                &lc.ancestors,
-                lc.layer,
-                false,
-            ),
+            LayoutElement::area(self, state, clipping_rect, &lc.ancestors, lc.layer, false)
+                .with_style(&style, state, focused),
         );
-        rect.left += self.style.padding.left;
-        rect.top += self.style.padding.top;
-        rect.right -= self.style.padding.right;
-        rect.bottom -= self.style.padding.bottom;
+        rect.left += padding.left;
+        rect.top += padding.top;
+        rect.right -= padding.right;
+        rect.bottom -= padding.bottom;
         let front_text = self.front_text.iter().collect::<String>();
         let front_rect = bounding_box_with_str(lc.ctx, font, &front_text);
         rect = LogicalRect::from_position_size(rect.left_top(), front_rect.size());
@@ -198,17 +268,20 @@ impl Widget for TextBox {
                 &lc,
                 LayoutElement::text(
                     self,
-                    self.widget_state,
+                    state,
                     rect,
                     &lc.ancestors,
                     Some(font.clone()),
                     front_text,
                     lc.layer,
                     lc.selected,
-                ),
+                )
+                .with_style(&style, state, focused),
             );
         }
-        if lc.ctx.has_focus(self) {
+        self.caret_rect.set(None);
+        if focused {
+            let caret_visible = self.is_caret_visible(lc.ctx);
             let cursor_char = self.back_text.last().cloned();
             let cursor_char_size = cursor_char
                 .map(|c| bounding_box_with_str(lc.ctx, font, &c.to_string()).size())
@@ -230,7 +303,7 @@ impl Widget for TextBox {
                         &lc,
                         LayoutElement::composition_text(
                             self,
-                            self.widget_state,
+                            state,
                             rect,
                             &lc.ancestors,
                             Some(font.clone()),
@@ -257,38 +330,44 @@ impl Widget for TextBox {
                 };
                 let cursor_rect = LogicalRect::from_position_size(
                     LogicalPosition::new(
-                        lc.rect.left + self.style.padding.left + front_rect.right + text_rect.right,
+                        lc.rect.left + padding.left + front_rect.right + text_rect.right,
                         rect.top,
                     ),
                     LogicalSize::new(cursor_char_size.width, rect.bottom - rect.top),
                 );
-                result.push(
-                    &lc,
-                    LayoutElement::cursor(
-                        self,
-                        self.widget_state,
-                        cursor_rect,
-                        &lc.ancestors,
-                        self.back_text.last().cloned(),
-                        lc.layer,
-                    ),
-                );
+                self.caret_rect.set(Some(cursor_rect));
+                if caret_visible {
+                    result.push(
+                        &lc,
+                        LayoutElement::cursor(
+                            self,
+                            state,
+                            cursor_rect,
+                            &lc.ancestors,
+                            self.back_text.last().cloned(),
+                            lc.layer,
+                        ),
+                    );
+                }
             } else {
                 let cursor_rect = LogicalRect::from_position_size(
                     LogicalPosition::new(rect.right, rect.top),
                     LogicalSize::new(cursor_char_size.width, rect.bottom - rect.top),
                 );
-                result.push(
-                    &lc,
-                    LayoutElement::cursor(
-                        self,
-                        self.widget_state,
-                        cursor_rect,
-                        &lc.ancestors,
-                        self.back_text.last().cloned(),
-                        lc.layer,
-                    ),
-                );
+                self.caret_rect.set(Some(cursor_rect));
+                if caret_visible {
+                    result.push(
+                        &lc,
+                        LayoutElement::cursor(
+                            self,
+                            state,
+                            cursor_rect,
+                            &lc.ancestors,
+                            self.back_text.last().cloned(),
+                            lc.layer,
+                        ),
+                    );
+                }
             }
         }
         if !self.back_text.is_empty() {
@@ -305,14 +384,15 @@ impl Widget for TextBox {
                 &lc,
                 LayoutElement::text(
                     self,
-                    self.widget_state,
+                    state,
                     rect,
                     &lc.ancestors,
                     Some(font.clone()),
                     back_text,
                     lc.layer,
                     lc.selected,
-                ),
+                )
+                .with_style(&style, state, focused),
             );
         }
         result.push(